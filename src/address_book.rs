@@ -0,0 +1,183 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{error::Error, parse_pubkey};
+
+/// Cluster whose addresses are used from the address book
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// Mainnet beta
+    #[default]
+    Mainnet,
+    /// Public devnet
+    Devnet,
+    /// Local test validator
+    Localnet,
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Network::Mainnet => "mainnet",
+            Network::Devnet => "devnet",
+            Network::Localnet => "localnet",
+        })
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "mainnet" => Ok(Network::Mainnet),
+            "devnet" => Ok(Network::Devnet),
+            "localnet" => Ok(Network::Localnet),
+            _ => Err(format!(
+                "unknown network `{}`, expected mainnet, devnet or localnet",
+                value
+            )),
+        }
+    }
+}
+
+/// Aliases of frequently used addresses of a single network, read from a TOML
+/// file with one table per network, e.g.
+///
+/// ```toml
+/// [mainnet]
+/// neon-evm = "NeonVMyRX5GbCrsAHnUwx1nYYoJAtskU1bWUo6JGNyG"
+///
+/// [devnet]
+/// neon-evm = "eeLSJgWzzxrqKv1UxtRVVH8FX3qCQWUs9QuAjJpETGU"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressBook {
+    addresses: BTreeMap<String, Pubkey>,
+}
+
+impl AddressBook {
+    /// Reads the aliases of the network from the address book file
+    pub fn read(path: &Path, network: Network) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut networks: BTreeMap<String, BTreeMap<String, String>> =
+            toml::from_str(&content).map_err(|err| Error::InvalidAddressBook(err.to_string()))?;
+
+        let addresses = networks
+            .remove(&network.to_string())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(alias, address)| Ok((alias, parse_pubkey(&address)?)))
+            .collect::<Result<_, Error>>()?;
+
+        Ok(AddressBook { addresses })
+    }
+
+    /// Resolves an alias or parses the value as a pubkey
+    pub fn resolve(&self, value: &str) -> Result<Pubkey, Error> {
+        match self.addresses.get(value) {
+            Some(address) => Ok(*address),
+            None => parse_pubkey(value),
+        }
+    }
+
+    /// Returns the alias of the address, if any
+    pub fn alias(&self, address: &Pubkey) -> Option<&str> {
+        self.addresses
+            .iter()
+            .find(|(_, a)| *a == address)
+            .map(|(alias, _)| alias.as_str())
+    }
+
+    /// Appends the alias to every known address found in the text
+    pub fn annotate(&self, text: &str) -> String {
+        self.addresses
+            .iter()
+            .fold(text.to_string(), |text, (alias, address)| {
+                let address = address.to_string();
+                text.replace(&address, &format!("{} [{}]", address, alias))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const NEON_EVM: &str = "NeonVMyRX5GbCrsAHnUwx1nYYoJAtskU1bWUo6JGNyG";
+
+    /// Writes the address book content to a file unique to the test
+    fn address_book_file(name: &str, content: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("address-book-{}-{}.toml", name, std::process::id()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn resolve_alias_and_pubkey() {
+        let path = address_book_file(
+            "resolve",
+            &format!("[mainnet]\nneon-evm = \"{}\"\n", NEON_EVM),
+        );
+        let book = AddressBook::read(&path, Network::Mainnet).unwrap();
+        let neon_evm = Pubkey::from_str(NEON_EVM).unwrap();
+
+        assert_eq!(book.resolve("neon-evm").unwrap(), neon_evm);
+        assert_eq!(book.resolve(NEON_EVM).unwrap(), neon_evm);
+        assert_eq!(book.alias(&neon_evm), Some("neon-evm"));
+        assert!(matches!(
+            book.resolve("unknown-alias"),
+            Err(Error::InvalidPubkey(_))
+        ));
+    }
+
+    #[test]
+    fn missing_network_table() {
+        let path = address_book_file(
+            "missing-network",
+            &format!("[mainnet]\nneon-evm = \"{}\"\n", NEON_EVM),
+        );
+        let book = AddressBook::read(&path, Network::Devnet).unwrap();
+
+        assert_eq!(book, AddressBook::default());
+        assert!(book.resolve("neon-evm").is_err());
+    }
+
+    #[test]
+    fn reject_invalid_pubkey() {
+        let path = address_book_file("invalid-pubkey", "[devnet]\nneon-evm = \"not-a-pubkey\"\n");
+
+        assert!(matches!(
+            AddressBook::read(&path, Network::Devnet),
+            Err(Error::InvalidPubkey(_))
+        ));
+    }
+
+    #[test]
+    fn annotate_known_addresses() {
+        let book = AddressBook {
+            addresses: BTreeMap::from([(
+                "neon-evm".to_string(),
+                Pubkey::from_str(NEON_EVM).unwrap(),
+            )]),
+        };
+
+        assert_eq!(
+            book.annotate(&format!(
+                "Program: {}\nOther: 11111111111111111111111111111111",
+                NEON_EVM
+            )),
+            format!(
+                "Program: {} [neon-evm]\nOther: 11111111111111111111111111111111",
+                NEON_EVM
+            )
+        );
+    }
+}
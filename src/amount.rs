@@ -0,0 +1,95 @@
+//! Parsing of decimal token amounts into base units, without floating point
+//! rounding
+
+use crate::error::Error;
+
+/// Number of decimals of SOL, one SOL is 10^9 lamports
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Parses a decimal amount of a token with the given decimals into base units,
+/// e.g. `1.5` with 6 decimals is 1500000
+pub fn parse_ui_amount(value: &str, decimals: u8) -> Result<u64, Error> {
+    let invalid = || Error::InvalidAmount(value.to_string());
+
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || fraction.len() > decimals as usize
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+    digits.parse().map_err(|_| invalid())
+}
+
+/// Parses an amount of SOL into lamports
+pub fn parse_sol(value: &str) -> Result<u64, Error> {
+    parse_ui_amount(value, SOL_DECIMALS)
+}
+
+/// Formats base units as a decimal amount of a token with the given decimals
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let digits = format!("{:0>width$}", amount, width = decimals as usize + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sol_amounts() {
+        assert_eq!(parse_ui_amount("1.5", 9).unwrap(), 1_500_000_000);
+        assert_eq!(parse_sol("0.000000001").unwrap(), 1);
+        assert_eq!(parse_sol(".5").unwrap(), 500_000_000);
+        assert_eq!(parse_sol("1.").unwrap(), 1_000_000_000);
+        assert_eq!(parse_sol("0").unwrap(), 0);
+    }
+
+    #[test]
+    fn parse_max_amount() {
+        assert_eq!(parse_sol("18446744073.709551615").unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn parse_invalid_amounts() {
+        for value in [
+            "1.0000000001",
+            "abc",
+            "1,5",
+            "-1",
+            "",
+            ".",
+            "18446744073.709551616",
+        ] {
+            assert!(
+                matches!(parse_sol(value), Err(Error::InvalidAmount(_))),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn format_amounts() {
+        assert_eq!(format_ui_amount(1_500_000_000, 9), "1.5");
+        assert_eq!(format_ui_amount(1, 9), "0.000000001");
+        assert_eq!(format_ui_amount(12_000_000, 6), "12");
+        assert_eq!(format_ui_amount(0, 0), "0");
+    }
+}
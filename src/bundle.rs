@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use solana_program::instruction::Instruction;
+
+use crate::{decoder::DecoderRegistry, encode, error::Error};
+
+/// Ordered instructions to be inserted into a single proposal
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bundle {
+    instructions: Vec<Instruction>,
+}
+
+impl Bundle {
+    /// Creates an empty bundle
+    pub fn new() -> Self {
+        Bundle::default()
+    }
+
+    /// Appends the instruction at the end of the bundle
+    pub fn push(&mut self, instruction: Instruction) {
+        self.instructions.push(instruction);
+    }
+
+    /// Instructions of the bundle in execution order
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Encodes every instruction of the bundle as base64 `InstructionData`, in order
+    pub fn encode(&self) -> Result<Vec<String>, Error> {
+        self.instructions.iter().map(encode).collect()
+    }
+
+    /// Summarises the bundle with one numbered line per instruction
+    pub fn summary(&self, registry: &DecoderRegistry) -> String {
+        let mut summary = format!("Bundle of {} instruction(s):", self.instructions.len());
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            let name = match registry.decode(&instruction.clone().into()) {
+                Ok(Some(decoded)) => format!("{} {}", decoded.program_name, decoded.name),
+                _ => format!("{} (unknown program)", instruction.program_id),
+            };
+            write!(
+                summary,
+                "\n  #{}: {} ({} accounts, {} bytes of data)",
+                index + 1,
+                name,
+                instruction.accounts.len(),
+                instruction.data.len()
+            )
+            .unwrap();
+        }
+        summary
+    }
+}
+
+impl From<Vec<Instruction>> for Bundle {
+    fn from(instructions: Vec<Instruction>) -> Self {
+        Bundle { instructions }
+    }
+}
+
+impl Extend<Instruction> for Bundle {
+    fn extend<T: IntoIterator<Item = Instruction>>(&mut self, iter: T) {
+        self.instructions.extend(iter);
+    }
+}
@@ -0,0 +1,1623 @@
+use std::{fs, path::PathBuf, sync::OnceLock};
+
+use clap::{error::ErrorKind, ArgGroup, Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use governance_upgrade_ix_base64_generator::{
+    address_book::{AddressBook, Network},
+    amount::{format_ui_amount, parse_sol, parse_ui_amount, SOL_DECIMALS},
+    decode,
+    error::Error,
+    governance::{
+        find_governance_authority,
+        instruction::{
+            add_signatory, cast_vote, create_proposal, execute_transaction, finalize_vote,
+            insert_transaction, set_governance_config, set_realm_authority, set_realm_config,
+            sign_off_proposal, AddSignatoryAuthority,
+        },
+        pda::{
+            get_governance_address, get_native_treasury_address, get_proposal_address,
+            get_realm_address, get_token_owner_record_address,
+        },
+        state::{
+            GovernanceConfig, GoverningTokenType, MintMaxVoterWeightSource, MultiChoiceType,
+            SetRealmAuthorityAction, Vote, VoteChoice, VoteThreshold, VoteTipping, VoteType,
+            MAX_PROPOSAL_OPTIONS,
+        },
+        GovernanceAuthority, DEFAULT_GOVERNANCE_PROGRAM_ID,
+    },
+    program_data::{check_upgrade_authority, extend_program_if_needed, read_account_dump},
+    token::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    treasury::{transfer_sol, transfer_tokens},
+    InstructionData,
+};
+use solana_program::{
+    bpf_loader_upgradeable::{
+        close_any, extend_program, get_program_data_address, set_buffer_authority,
+        set_buffer_authority_checked, set_upgrade_authority, set_upgrade_authority_checked,
+        upgrade,
+    },
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+
+/// Generates base64 encoded instructions to be inserted into governance proposals
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(flatten)]
+    pub profile: ProfileArgs,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Options selecting the address book, they must precede the subcommand
+#[derive(Debug, Args)]
+pub struct ProfileArgs {
+    /// TOML file mapping aliases to addresses, with one table per network
+    #[arg(long)]
+    pub address_book: Option<PathBuf>,
+    /// Network whose aliases are used from the address book
+    #[arg(long, default_value_t = Network::Mainnet)]
+    pub network: Network,
+}
+
+/// Parser of the profile options alone, the subcommand is left unparsed
+#[derive(Debug, Parser)]
+#[command(
+    ignore_errors = true,
+    disable_help_flag = true,
+    disable_version_flag = true
+)]
+struct ProfileParser {
+    #[command(flatten)]
+    profile: ProfileArgs,
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    _command: Vec<String>,
+}
+
+/// Address book resolving aliases while the pubkey arguments are parsed
+static ADDRESS_BOOK: OnceLock<AddressBook> = OnceLock::new();
+
+/// Loads the address book selected on the command line, it has to be called
+/// before parsing [`Cli`] for aliases to be resolved
+pub fn load_address_book() -> Result<(), Error> {
+    // invalid profile options are left to `Cli::try_parse` to report with its usage
+    let Ok(ProfileParser { profile, .. }) = ProfileParser::try_parse() else {
+        return Ok(());
+    };
+    let address_book = match profile.address_book {
+        Some(path) => AddressBook::read(&path, profile.network)?,
+        None => AddressBook::default(),
+    };
+
+    let _ = ADDRESS_BOOK.set(address_book);
+    Ok(())
+}
+
+/// Returns the loaded address book, empty if none was selected
+pub fn address_book() -> &'static AddressBook {
+    ADDRESS_BOOK.get_or_init(AddressBook::default)
+}
+
+/// Parses a pubkey argument, resolving the aliases of the address book
+fn parse_address(value: &str) -> Result<Pubkey, Error> {
+    address_book().resolve(value)
+}
+
+/// Commands supported by the generator
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Decodes a base64 encoded instruction into a human-readable form
+    Decode {
+        /// Base64 encoded `InstructionData`, as inserted into a proposal
+        encoded: String,
+        /// Address of a custom spl-governance program instance to decode
+        #[arg(long, value_parser = parse_address)]
+        governance_program: Option<Pubkey>,
+        /// Fails instead of printing raw data when the program has no known decoder
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Builds several instructions to be inserted into one proposal, in order
+    #[command(
+        after_help = "Example:\n  bundle extend-program --program <PROGRAM> --additional-bytes 1024 \\\n    + upgrade --program <PROGRAM> --buffer <BUFFER> --authority <AUTHORITY>"
+    )]
+    Bundle {
+        /// Instruction subcommands with their arguments, separated with `+`
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        steps: Vec<String>,
+    },
+    /// Builds the instructions of a proposal described in a TOML or JSON manifest
+    Manifest {
+        /// Path to the manifest, `.json` files are parsed as JSON and anything else as TOML
+        path: PathBuf,
+    },
+    /// Derives the addresses of spl-governance accounts
+    Derive(DeriveArgs),
+    #[command(flatten)]
+    Instruction(InstructionCommand),
+}
+
+/// Inputs of the spl-governance address derivation, every address derivable
+/// from the given inputs is printed
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("realm_source").required(true).args(["realm", "realm_name"])))]
+pub struct DeriveArgs {
+    /// Address of the spl-governance program instance
+    #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+    pub governance_program: Pubkey,
+    /// Address of the realm
+    #[arg(long, value_parser = parse_address)]
+    pub realm: Option<Pubkey>,
+    /// Name of the realm, used to derive its address
+    #[arg(long)]
+    pub realm_name: Option<String>,
+    /// Account governed by the governance (the governance seed)
+    #[arg(long, value_parser = parse_address)]
+    pub governed_account: Option<Pubkey>,
+    /// Governing token mint (community or council)
+    #[arg(long, value_parser = parse_address)]
+    pub governing_token_mint: Option<Pubkey>,
+    /// Owner of the governing tokens, for the token owner record
+    #[arg(long, value_parser = parse_address)]
+    pub governing_token_owner: Option<Pubkey>,
+    /// Seed of the proposal
+    #[arg(long, value_parser = parse_address)]
+    pub proposal_seed: Option<Pubkey>,
+}
+
+impl DeriveArgs {
+    /// Derives the addresses available from the inputs, labelled by account
+    pub fn addresses(&self) -> Vec<(&'static str, Pubkey)> {
+        let program_id = &self.governance_program;
+        let mut addresses = vec![];
+
+        let realm = match (&self.realm, &self.realm_name) {
+            (Some(realm), _) => *realm,
+            (None, Some(name)) => get_realm_address(program_id, name),
+            // clap requires either the realm or its name
+            (None, None) => return addresses,
+        };
+        addresses.push(("Realm", realm));
+
+        if let (Some(mint), Some(owner)) = (&self.governing_token_mint, &self.governing_token_owner)
+        {
+            addresses.push((
+                "Token owner record",
+                get_token_owner_record_address(program_id, &realm, mint, owner),
+            ));
+        }
+
+        if let Some(governed_account) = &self.governed_account {
+            let governance = get_governance_address(program_id, &realm, governed_account);
+            addresses.push(("Governance", governance));
+            addresses.push((
+                "Native treasury",
+                get_native_treasury_address(program_id, &governance),
+            ));
+
+            if let (Some(mint), Some(seed)) = (&self.governing_token_mint, &self.proposal_seed) {
+                addresses.push((
+                    "Proposal",
+                    get_proposal_address(program_id, &governance, mint, seed),
+                ));
+            }
+        }
+
+        addresses
+    }
+}
+
+// Single step of a bundle, parsed separately from the main command line. The
+// `help` subcommand is disabled so that a step can only be an instruction
+#[derive(Debug, Parser)]
+#[command(
+    name = "bundle",
+    about = "Instruction subcommand of a bundle step",
+    no_binary_name = true,
+    disable_help_subcommand = true
+)]
+struct BundleStep {
+    #[command(subcommand)]
+    command: InstructionCommand,
+}
+
+/// Parses the steps of a bundle separated with `+`
+pub fn parse_bundle_steps(steps: &[String]) -> Result<Vec<InstructionCommand>, clap::Error> {
+    steps.split(|arg| arg == "+").map(parse_step).collect()
+}
+
+/// Parses a single instruction subcommand with its arguments
+pub fn parse_step(args: &[String]) -> Result<InstructionCommand, clap::Error> {
+    if args.is_empty() {
+        return Err(BundleStep::command().error(
+            ErrorKind::MissingSubcommand,
+            "empty bundle step, expected an instruction subcommand between `+` separators",
+        ));
+    }
+    BundleStep::try_parse_from(args).map(|step| step.command)
+}
+
+/// Realm of the governance expected to hold the authority of a program
+#[derive(Debug, Args)]
+pub struct RealmArgs {
+    /// Realm of the governance, enables the governance authority sanity check
+    #[arg(long, value_parser = parse_address)]
+    pub realm: Option<Pubkey>,
+    /// Account governed by the governance, defaults to the program
+    #[arg(long, value_parser = parse_address, requires = "realm")]
+    pub governed_account: Option<Pubkey>,
+    /// Address of the spl-governance program instance
+    #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+    pub governance_program: Pubkey,
+}
+
+impl RealmArgs {
+    /// Detects whether the authority is the governance of the program or its
+    /// native treasury. Returns a note when it is either and a warning when it
+    /// is neither, `None` without a realm
+    fn check_authority(
+        &self,
+        program: &Pubkey,
+        authority: &Pubkey,
+    ) -> Option<Result<String, String>> {
+        let realm = self.realm.as_ref()?;
+        let governed_account = self.governed_account.as_ref().unwrap_or(program);
+
+        Some(
+            match find_governance_authority(
+                &self.governance_program,
+                realm,
+                governed_account,
+                authority,
+            ) {
+                Some(GovernanceAuthority::Governance) => {
+                    Ok(format!("authority {} is the governance PDA", authority))
+                }
+                Some(GovernanceAuthority::NativeTreasury) => Ok(format!(
+                    "authority {} is the native treasury PDA of the governance",
+                    authority
+                )),
+                None => {
+                    let governance =
+                        get_governance_address(&self.governance_program, realm, governed_account);
+                    Err(format!(
+                        "authority {} is neither the governance {} nor its native treasury {}",
+                        authority,
+                        governance,
+                        get_native_treasury_address(&self.governance_program, &governance)
+                    ))
+                }
+            },
+        )
+    }
+}
+
+/// Amount of SOL given either in SOL or in lamports
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct SolAmountArgs {
+    /// Amount in SOL, e.g. 1.5
+    #[arg(long, value_parser = parse_sol)]
+    pub sol: Option<u64>,
+    /// Amount in lamports
+    #[arg(long)]
+    pub lamports: Option<u64>,
+}
+
+impl SolAmountArgs {
+    /// Returns the amount in lamports, clap requires exactly one of the amounts
+    fn lamports(&self) -> Result<u64, Error> {
+        match (self.sol, self.lamports) {
+            (Some(lamports), None) | (None, Some(lamports)) => Ok(lamports),
+            _ => Err(Error::InvalidArgument(
+                "either --sol or --lamports is required".to_string(),
+            )),
+        }
+    }
+}
+
+/// Governance config replacing the current one as a whole
+#[derive(Debug, Args)]
+pub struct GovernanceConfigArgs {
+    /// Community vote threshold, a percentage of Yes votes or `disabled`
+    #[arg(long)]
+    pub community_vote_threshold: VoteThreshold,
+    /// Minimum community weight to create a proposal
+    #[arg(long)]
+    pub min_community_weight_to_create_proposal: u64,
+    /// Minimum seconds between the end of voting and the execution of a transaction
+    #[arg(long)]
+    pub min_transaction_hold_up_time: u32,
+    /// Seconds a proposal is open for voting
+    #[arg(long)]
+    pub voting_base_time: u32,
+    /// Early completion of community votes: strict, early or disabled
+    #[arg(long)]
+    pub community_vote_tipping: VoteTipping,
+    /// Council vote threshold, a percentage of Yes votes or `disabled`
+    #[arg(long)]
+    pub council_vote_threshold: VoteThreshold,
+    /// Council veto vote threshold, a percentage or `disabled`
+    #[arg(long)]
+    pub council_veto_vote_threshold: VoteThreshold,
+    /// Minimum council weight to create a proposal
+    #[arg(long)]
+    pub min_council_weight_to_create_proposal: u64,
+    /// Early completion of council votes: strict, early or disabled
+    #[arg(long)]
+    pub council_vote_tipping: VoteTipping,
+    /// Community veto vote threshold, a percentage or `disabled`
+    #[arg(long)]
+    pub community_veto_vote_threshold: VoteThreshold,
+    /// Seconds after the voting time during which only vetoes and relinquishes are allowed
+    #[arg(long)]
+    pub voting_cool_off_time: u32,
+    /// Number of proposals of a token owner exempt from the proposal deposit
+    #[arg(long)]
+    pub deposit_exempt_proposal_count: u8,
+}
+
+impl From<&GovernanceConfigArgs> for GovernanceConfig {
+    fn from(args: &GovernanceConfigArgs) -> Self {
+        GovernanceConfig {
+            community_vote_threshold: args.community_vote_threshold.clone(),
+            min_community_weight_to_create_proposal: args.min_community_weight_to_create_proposal,
+            min_transaction_hold_up_time: args.min_transaction_hold_up_time,
+            voting_base_time: args.voting_base_time,
+            community_vote_tipping: args.community_vote_tipping.clone(),
+            council_vote_threshold: args.council_vote_threshold.clone(),
+            council_veto_vote_threshold: args.council_veto_vote_threshold.clone(),
+            min_council_weight_to_create_proposal: args.min_council_weight_to_create_proposal,
+            council_vote_tipping: args.council_vote_tipping.clone(),
+            community_veto_vote_threshold: args.community_veto_vote_threshold.clone(),
+            voting_cool_off_time: args.voting_cool_off_time,
+            deposit_exempt_proposal_count: args.deposit_exempt_proposal_count,
+        }
+    }
+}
+
+/// Vote cast on a proposal
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum VoteArg {
+    /// Approves the proposal
+    Approve,
+    /// Rejects the proposal
+    Deny,
+    /// Declares indifference to the proposal
+    Abstain,
+    /// Vetoes the proposal, with the other governing token
+    Veto,
+}
+
+impl From<VoteArg> for Vote {
+    fn from(vote: VoteArg) -> Self {
+        match vote {
+            VoteArg::Approve => Vote::Approve(vec![VoteChoice {
+                rank: 0,
+                weight_percentage: 100,
+            }]),
+            VoteArg::Deny => Vote::Deny,
+            VoteArg::Abstain => Vote::Abstain,
+            VoteArg::Veto => Vote::Veto,
+        }
+    }
+}
+
+/// Instructions supported by the generator
+#[derive(Debug, Subcommand)]
+pub enum InstructionCommand {
+    /// Transfers the upgrade authority of a program to a new address
+    SetUpgradeAuthority {
+        /// Address of the upgradeable program
+        #[arg(long, value_parser = parse_address)]
+        program: Pubkey,
+        /// Current upgrade authority of the program (usually the governance)
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+        /// Address to become the new upgrade authority
+        #[arg(long, value_parser = parse_address)]
+        new_authority: Pubkey,
+        /// Emits the checked variant which requires the new authority to sign as well,
+        /// not possible for PDAs such as a governance
+        #[arg(long)]
+        checked: bool,
+        /// Local dump of the ProgramData account, checked to be upgradeable by the authority
+        #[arg(long)]
+        program_data_dump: Option<PathBuf>,
+        #[command(flatten)]
+        realm: RealmArgs,
+    },
+    /// Removes the upgrade authority of a program, making it immutable forever
+    MakeImmutable {
+        /// Address of the upgradeable program
+        #[arg(long, value_parser = parse_address)]
+        program: Pubkey,
+        /// Current upgrade authority of the program (usually the governance)
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+        /// Confirms that the program can never be upgraded again once executed
+        #[arg(long, required = true)]
+        confirm_irreversible: bool,
+        /// Local dump of the ProgramData account, checked to be upgradeable by the authority
+        #[arg(long)]
+        program_data_dump: Option<PathBuf>,
+        #[command(flatten)]
+        realm: RealmArgs,
+    },
+    /// Transfers the authority of a buffer account to a new address
+    SetBufferAuthority {
+        /// Buffer account holding the program binary
+        #[arg(long, value_parser = parse_address)]
+        buffer: Pubkey,
+        /// Current authority of the buffer
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+        /// Address to become the new buffer authority (usually the governance)
+        #[arg(long, value_parser = parse_address)]
+        new_authority: Pubkey,
+        /// Emits the checked variant which requires the new authority to sign as well,
+        /// not possible for PDAs such as a governance
+        #[arg(long)]
+        checked: bool,
+    },
+    /// Closes a buffer account and withdraws its lamports
+    CloseBuffer {
+        /// Buffer account to be closed
+        #[arg(long, value_parser = parse_address)]
+        buffer: Pubkey,
+        /// Account receiving the buffer lamports
+        #[arg(long, value_parser = parse_address)]
+        recipient: Pubkey,
+        /// Authority of the buffer
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+    },
+    /// Closes a program together with its ProgramData account and withdraws its lamports
+    CloseProgram {
+        /// Address of the upgradeable program
+        #[arg(long, value_parser = parse_address)]
+        program: Pubkey,
+        /// Account receiving the ProgramData lamports
+        #[arg(long, value_parser = parse_address)]
+        recipient: Pubkey,
+        /// Upgrade authority of the program (usually the governance)
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+        /// Confirms that the program address can never be used again once executed
+        #[arg(long, required = true)]
+        confirm_irreversible: bool,
+        #[command(flatten)]
+        realm: RealmArgs,
+    },
+    /// Extends the ProgramData account of a program to fit a larger binary
+    ExtendProgram {
+        /// Address of the upgradeable program
+        #[arg(long, value_parser = parse_address)]
+        program: Pubkey,
+        /// Account funding the rent for the extra bytes, omit if ProgramData is already funded
+        #[arg(long, value_parser = parse_address)]
+        payer: Option<Pubkey>,
+        /// Number of bytes to add to the ProgramData account
+        #[arg(long)]
+        additional_bytes: u32,
+    },
+    /// Upgrades a program with the binary written to a buffer account
+    Upgrade {
+        /// Address of the upgradeable program
+        #[arg(long, value_parser = parse_address)]
+        program: Pubkey,
+        /// Buffer account holding the new program binary
+        #[arg(long, value_parser = parse_address)]
+        buffer: Pubkey,
+        /// Upgrade authority of the program (usually the governance)
+        #[arg(long, value_parser = parse_address)]
+        authority: Pubkey,
+        /// Account receiving the buffer lamports, defaults to the authority
+        #[arg(long, value_parser = parse_address)]
+        spill: Option<Pubkey>,
+        /// Local copy of the new program binary (.so), used to extend ProgramData when needed
+        #[arg(long, requires = "program_data_dump")]
+        program_binary: Option<PathBuf>,
+        /// Local dump of the current ProgramData account, as saved by `solana account`.
+        /// Its upgrade authority is checked to match the authority
+        #[arg(long)]
+        program_data_dump: Option<PathBuf>,
+        /// Account funding the rent for the ProgramData extension, required when
+        /// ProgramData is too small for the binary
+        #[arg(long, value_parser = parse_address, requires = "program_binary")]
+        extend_payer: Option<Pubkey>,
+        #[command(flatten)]
+        realm: RealmArgs,
+    },
+    /// Inserts base64 encoded instructions into a proposal as one spl-governance transaction
+    InsertTransaction {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal receiving the transaction
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Token owner record of the proposal owner
+        #[arg(long, value_parser = parse_address)]
+        token_owner_record: Pubkey,
+        /// Owner or delegate of the token owner record
+        #[arg(long, value_parser = parse_address)]
+        governance_authority: Pubkey,
+        /// Account paying for the transaction account, defaults to the governance authority
+        #[arg(long, value_parser = parse_address)]
+        payer: Option<Pubkey>,
+        /// Index of the proposal option the transaction is executed for
+        #[arg(long, default_value_t = 0)]
+        option_index: u8,
+        /// Index of the transaction within the option
+        #[arg(long)]
+        index: u16,
+        /// Seconds between the end of voting and the transaction becoming executable,
+        /// at least the minimum hold-up time of the governance
+        #[arg(long)]
+        hold_up_time: u32,
+        /// Base64 encoded `InstructionData` of the transaction, repeated in execution order
+        #[arg(long = "instruction", value_parser = decode, required = true)]
+        instructions: Vec<InstructionData>,
+    },
+    /// Creates a proposal in the governance, its address is derived from the seed
+    CreateProposal {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm of the governance
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Governance the proposal is created in
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Token owner record of the proposal owner
+        #[arg(long, value_parser = parse_address)]
+        proposal_owner_record: Pubkey,
+        /// Owner or delegate of the proposal owner record
+        #[arg(long, value_parser = parse_address)]
+        governance_authority: Pubkey,
+        /// Account paying for the proposal accounts, defaults to the governance authority
+        #[arg(long, value_parser = parse_address)]
+        payer: Option<Pubkey>,
+        /// Governing token mint (community or council) voting on the proposal
+        #[arg(long, value_parser = parse_address)]
+        governing_token_mint: Pubkey,
+        /// Name of the proposal
+        #[arg(long)]
+        name: String,
+        /// Link to a description of the proposal
+        #[arg(long, default_value = "")]
+        description_link: String,
+        /// Option of the proposal, repeated for multiple choice proposals (2 to 10 options)
+        #[arg(long = "option", default_value = "Approve")]
+        options: Vec<String>,
+        /// Lets voters choose any number of options instead of a single one
+        #[arg(long)]
+        multi_choice: bool,
+        /// Creates the proposal without the deny option, for surveys
+        #[arg(long)]
+        no_deny_option: bool,
+        /// Unique seed of the proposal address
+        #[arg(long, value_parser = parse_address)]
+        proposal_seed: Pubkey,
+        /// Voter weight record of the voter weight addin, if the realm uses one
+        #[arg(long, value_parser = parse_address)]
+        voter_weight_record: Option<Pubkey>,
+    },
+    /// Adds a signatory to a draft proposal
+    AddSignatory {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal receiving the signatory
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Signatory to be added
+        #[arg(long, value_parser = parse_address)]
+        signatory: Pubkey,
+        /// Token owner record of the proposal owner, omit to add a signatory required by the governance
+        #[arg(long, value_parser = parse_address, requires = "governance_authority")]
+        token_owner_record: Option<Pubkey>,
+        /// Owner or delegate of the proposal owner record
+        #[arg(long, value_parser = parse_address, requires = "token_owner_record")]
+        governance_authority: Option<Pubkey>,
+        /// Account paying for the signatory record, defaults to the governance authority
+        #[arg(long, value_parser = parse_address, required_unless_present = "governance_authority")]
+        payer: Option<Pubkey>,
+    },
+    /// Signs off a draft proposal, as a signatory or as the proposal owner
+    SignOffProposal {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm of the governance
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal to be signed off
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Signatory, or the proposal owner when signing off without signatories
+        #[arg(long, value_parser = parse_address)]
+        signatory: Pubkey,
+        /// Token owner record of the proposal owner, when it signs off
+        #[arg(long, value_parser = parse_address)]
+        proposal_owner_record: Option<Pubkey>,
+    },
+    /// Casts a vote on a proposal
+    CastVote {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm of the governance
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal voted on
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Token owner record of the proposal owner
+        #[arg(long, value_parser = parse_address)]
+        proposal_owner_record: Pubkey,
+        /// Token owner record of the voter
+        #[arg(long, value_parser = parse_address)]
+        voter_token_owner_record: Pubkey,
+        /// Owner or delegate of the voter token owner record
+        #[arg(long, value_parser = parse_address)]
+        governance_authority: Pubkey,
+        /// Governing token mint (community or council) of the voter
+        #[arg(long, value_parser = parse_address)]
+        governing_token_mint: Pubkey,
+        /// Account paying for the vote record, defaults to the governance authority
+        #[arg(long, value_parser = parse_address)]
+        payer: Option<Pubkey>,
+        /// Voter weight record of the voter weight addin, if the realm uses one
+        #[arg(long, value_parser = parse_address)]
+        voter_weight_record: Option<Pubkey>,
+        /// Max voter weight record of the addin, if the realm uses one
+        #[arg(long, value_parser = parse_address)]
+        max_voter_weight_record: Option<Pubkey>,
+        /// Vote, approving votes for the single option of the proposal
+        #[arg(long)]
+        vote: VoteArg,
+    },
+    /// Finalizes the vote of a proposal once its voting time is over
+    FinalizeVote {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm of the governance
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal to be finalized
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Token owner record of the proposal owner
+        #[arg(long, value_parser = parse_address)]
+        proposal_owner_record: Pubkey,
+        /// Governing token mint (community or council) voting on the proposal
+        #[arg(long, value_parser = parse_address)]
+        governing_token_mint: Pubkey,
+        /// Max voter weight record of the addin, if the realm uses one
+        #[arg(long, value_parser = parse_address)]
+        max_voter_weight_record: Option<Pubkey>,
+    },
+    /// Executes a transaction of a succeeded proposal
+    ExecuteTransaction {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance of the proposal
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Proposal holding the transaction
+        #[arg(long, value_parser = parse_address)]
+        proposal: Pubkey,
+        /// Index of the proposal option the transaction is executed for
+        #[arg(long, default_value_t = 0)]
+        option_index: u8,
+        /// Index of the transaction within the option
+        #[arg(long)]
+        index: u16,
+        /// Base64 encoded `InstructionData` of the transaction, repeated in execution order
+        #[arg(long = "instruction", value_parser = decode, required = true)]
+        instructions: Vec<InstructionData>,
+    },
+    /// Replaces the config of a governance, executed by a proposal of that governance
+    SetGovernanceConfig {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance whose config is replaced, it signs when the proposal is executed
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        #[command(flatten)]
+        config: GovernanceConfigArgs,
+    },
+    /// Replaces the config of a realm, executed by a proposal of the realm authority
+    SetRealmConfig {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm whose config is replaced
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Authority of the realm (usually a governance)
+        #[arg(long, value_parser = parse_address)]
+        realm_authority: Pubkey,
+        /// Account paying for the realm config account, defaults to the realm authority
+        #[arg(long, value_parser = parse_address)]
+        payer: Option<Pubkey>,
+        /// Council token mint of the realm
+        #[arg(long, value_parser = parse_address, required_unless_present = "remove_council")]
+        council_mint: Option<Pubkey>,
+        /// Removes the council instead, it can never be set again
+        #[arg(
+            long,
+            conflicts_with = "council_mint",
+            requires = "confirm_irreversible"
+        )]
+        remove_council: bool,
+        /// Confirms that the council can never be set again once removed
+        #[arg(long, requires = "remove_council")]
+        confirm_irreversible: bool,
+        /// Minimum community weight to create a governance
+        #[arg(long)]
+        min_community_weight_to_create_governance: u64,
+        /// Max voter weight of the community mint, supply-fraction:<fraction>
+        /// (10000000000 being the full supply) or absolute:<weight>
+        #[arg(long)]
+        community_mint_max_voter_weight_source: MintMaxVoterWeightSource,
+        /// Type of the community token: liquid, membership or dormant
+        #[arg(long)]
+        community_token_type: GoverningTokenType,
+        /// Type of the council token: liquid, membership or dormant
+        #[arg(long)]
+        council_token_type: GoverningTokenType,
+        /// Voter weight addin program of the community, omit to use token deposits
+        #[arg(long, value_parser = parse_address)]
+        community_voter_weight_addin: Option<Pubkey>,
+        /// Max voter weight addin program of the community
+        #[arg(long, value_parser = parse_address)]
+        max_community_voter_weight_addin: Option<Pubkey>,
+        /// Voter weight addin program of the council, omit to use token deposits
+        #[arg(long, value_parser = parse_address)]
+        council_voter_weight_addin: Option<Pubkey>,
+        /// Max voter weight addin program of the council
+        #[arg(long, value_parser = parse_address)]
+        max_council_voter_weight_addin: Option<Pubkey>,
+    },
+    /// Transfers or removes the authority of a realm
+    SetRealmAuthority {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Realm whose authority is changed
+        #[arg(long, value_parser = parse_address)]
+        realm: Pubkey,
+        /// Current authority of the realm (usually a governance)
+        #[arg(long, value_parser = parse_address)]
+        realm_authority: Pubkey,
+        /// Address to become the realm authority, checked to be a governance of the realm
+        #[arg(long, value_parser = parse_address, required_unless_present = "remove")]
+        new_realm_authority: Option<Pubkey>,
+        /// Skips the check that the new authority is a governance of the realm
+        #[arg(long, requires = "new_realm_authority")]
+        unchecked: bool,
+        /// Removes the realm authority instead, so the realm config can never change again
+        #[arg(
+            long,
+            conflicts_with = "new_realm_authority",
+            requires = "confirm_irreversible"
+        )]
+        remove: bool,
+        /// Confirms that the realm authority can never be set again once removed
+        #[arg(long, requires = "remove")]
+        confirm_irreversible: bool,
+    },
+    /// Transfers SOL from the native treasury of a governance
+    TransferSol {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance owning the native treasury
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Account receiving the SOL
+        #[arg(long, value_parser = parse_address)]
+        recipient: Pubkey,
+        #[command(flatten)]
+        amount: SolAmountArgs,
+    },
+    /// Transfers SPL tokens from a treasury token account of a governance
+    TransferTokens {
+        /// Address of the spl-governance program instance
+        #[arg(long, value_parser = parse_address, default_value_t = DEFAULT_GOVERNANCE_PROGRAM_ID)]
+        governance_program: Pubkey,
+        /// Governance owning the treasury
+        #[arg(long, value_parser = parse_address)]
+        governance: Pubkey,
+        /// Owner of the source associated token account: governance or native-treasury
+        #[arg(long, default_value = "native-treasury")]
+        treasury_owner: GovernanceAuthority,
+        /// Mint of the transferred tokens
+        #[arg(long, value_parser = parse_address)]
+        mint: Pubkey,
+        /// Wallet receiving the tokens in its associated token account
+        #[arg(long, value_parser = parse_address)]
+        recipient: Pubkey,
+        /// Amount in tokens, e.g. 1.5
+        #[arg(long)]
+        amount: String,
+        /// Decimals of the mint
+        #[arg(long)]
+        decimals: u8,
+        /// Uses the Token-2022 program instead of SPL Token
+        #[arg(long)]
+        token_2022: bool,
+    },
+}
+
+impl InstructionCommand {
+    /// Builds the instructions described by the command
+    pub fn instructions(&self) -> Result<Vec<Instruction>, Error> {
+        let instructions = match self {
+            InstructionCommand::SetUpgradeAuthority {
+                program,
+                authority,
+                new_authority,
+                checked,
+                program_data_dump,
+                realm: _,
+            } => {
+                check_program_data_authority(program, program_data_dump, authority)?;
+
+                if *checked {
+                    vec![set_upgrade_authority_checked(
+                        program,
+                        authority,
+                        new_authority,
+                    )]
+                } else {
+                    vec![set_upgrade_authority(
+                        program,
+                        authority,
+                        Some(new_authority),
+                    )]
+                }
+            }
+            InstructionCommand::MakeImmutable {
+                program,
+                authority,
+                program_data_dump,
+                ..
+            } => {
+                check_program_data_authority(program, program_data_dump, authority)?;
+
+                vec![set_upgrade_authority(program, authority, None)]
+            }
+            InstructionCommand::SetBufferAuthority {
+                buffer,
+                authority,
+                new_authority,
+                checked,
+            } => {
+                if *checked {
+                    vec![set_buffer_authority_checked(
+                        buffer,
+                        authority,
+                        new_authority,
+                    )]
+                } else {
+                    vec![set_buffer_authority(buffer, authority, new_authority)]
+                }
+            }
+            InstructionCommand::CloseBuffer {
+                buffer,
+                recipient,
+                authority,
+            } => vec![close_any(buffer, recipient, Some(authority), None)],
+            InstructionCommand::CloseProgram {
+                program,
+                recipient,
+                authority,
+                ..
+            } => vec![close_any(
+                &get_program_data_address(program),
+                recipient,
+                Some(authority),
+                Some(program),
+            )],
+            InstructionCommand::ExtendProgram {
+                program,
+                payer,
+                additional_bytes,
+            } => vec![extend_program(program, payer.as_ref(), *additional_bytes)],
+            InstructionCommand::Upgrade {
+                program,
+                buffer,
+                authority,
+                spill,
+                program_binary,
+                program_data_dump,
+                extend_payer,
+                realm: _,
+            } => {
+                let mut instructions = vec![];
+
+                if let Some(program_data_dump) = program_data_dump {
+                    let program_data =
+                        read_account_dump(program_data_dump, &get_program_data_address(program))?;
+                    check_upgrade_authority(&program_data, authority)?;
+
+                    // ProgramData must be large enough to hold the new binary before the upgrade
+                    if let Some(program_binary) = program_binary {
+                        let program_len = fs::metadata(program_binary)
+                            .map_err(|source| Error::Io {
+                                path: program_binary.clone(),
+                                source,
+                            })?
+                            .len() as usize;
+
+                        instructions.extend(extend_program_if_needed(
+                            program,
+                            extend_payer.as_ref(),
+                            &program_data,
+                            program_len,
+                        )?);
+                    }
+                }
+
+                instructions.push(upgrade(
+                    program,
+                    buffer,
+                    authority,
+                    spill.as_ref().unwrap_or(authority),
+                ));
+                instructions
+            }
+            InstructionCommand::InsertTransaction {
+                governance_program,
+                governance,
+                proposal,
+                token_owner_record,
+                governance_authority,
+                payer,
+                option_index,
+                index,
+                hold_up_time,
+                instructions,
+            } => vec![insert_transaction(
+                governance_program,
+                governance,
+                proposal,
+                token_owner_record,
+                governance_authority,
+                payer.as_ref().unwrap_or(governance_authority),
+                *option_index,
+                *index,
+                *hold_up_time,
+                instructions.clone(),
+            )],
+            InstructionCommand::CreateProposal {
+                governance_program,
+                realm,
+                governance,
+                proposal_owner_record,
+                governance_authority,
+                payer,
+                governing_token_mint,
+                name,
+                description_link,
+                options,
+                multi_choice,
+                no_deny_option,
+                proposal_seed,
+                voter_weight_record,
+            } => {
+                // a multiple choice proposal needs at least two options to choose from
+                let min_options = if *multi_choice { 2 } else { 1 };
+                let options_count = u8::try_from(options.len())
+                    .ok()
+                    .filter(|count| (min_options..=MAX_PROPOSAL_OPTIONS).contains(count))
+                    .ok_or_else(|| {
+                        Error::InvalidArgument(format!(
+                            "expected from {} to {} proposal options{}, got {}",
+                            min_options,
+                            MAX_PROPOSAL_OPTIONS,
+                            if *multi_choice {
+                                " with --multi-choice"
+                            } else {
+                                ""
+                            },
+                            options.len()
+                        ))
+                    })?;
+                let vote_type = if *multi_choice {
+                    VoteType::MultiChoice {
+                        choice_type: MultiChoiceType::FullWeight,
+                        min_voter_options: 1,
+                        max_voter_options: options_count,
+                        max_winning_options: options_count,
+                    }
+                } else {
+                    VoteType::SingleChoice
+                };
+
+                vec![create_proposal(
+                    governance_program,
+                    governance,
+                    proposal_owner_record,
+                    governance_authority,
+                    payer.as_ref().unwrap_or(governance_authority),
+                    voter_weight_record.as_ref(),
+                    realm,
+                    name.clone(),
+                    description_link.clone(),
+                    governing_token_mint,
+                    vote_type,
+                    options.clone(),
+                    !no_deny_option,
+                    proposal_seed,
+                )]
+            }
+            InstructionCommand::AddSignatory {
+                governance_program,
+                governance,
+                proposal,
+                signatory,
+                token_owner_record,
+                governance_authority,
+                payer,
+            } => {
+                // clap requires the governance authority and the token owner record
+                // together, and the payer without them
+                let (authority, payer) = match (governance_authority, token_owner_record, payer) {
+                    (Some(governance_authority), Some(token_owner_record), payer) => (
+                        AddSignatoryAuthority::ProposalOwner {
+                            governance_authority: *governance_authority,
+                            token_owner_record: *token_owner_record,
+                        },
+                        payer.unwrap_or(*governance_authority),
+                    ),
+                    (None, None, Some(payer)) => (AddSignatoryAuthority::None, *payer),
+                    _ => {
+                        return Err(Error::InvalidArgument(
+                            "either the governance authority with the token owner record or the payer is required"
+                                .to_string(),
+                        ))
+                    }
+                };
+
+                vec![add_signatory(
+                    governance_program,
+                    governance,
+                    proposal,
+                    &authority,
+                    &payer,
+                    signatory,
+                )]
+            }
+            InstructionCommand::SignOffProposal {
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                signatory,
+                proposal_owner_record,
+            } => vec![sign_off_proposal(
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                signatory,
+                proposal_owner_record.as_ref(),
+            )],
+            InstructionCommand::CastVote {
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                proposal_owner_record,
+                voter_token_owner_record,
+                governance_authority,
+                governing_token_mint,
+                payer,
+                voter_weight_record,
+                max_voter_weight_record,
+                vote,
+            } => vec![cast_vote(
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                proposal_owner_record,
+                voter_token_owner_record,
+                governance_authority,
+                governing_token_mint,
+                payer.as_ref().unwrap_or(governance_authority),
+                voter_weight_record.as_ref(),
+                max_voter_weight_record.as_ref(),
+                (*vote).into(),
+            )],
+            InstructionCommand::FinalizeVote {
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                proposal_owner_record,
+                governing_token_mint,
+                max_voter_weight_record,
+            } => vec![finalize_vote(
+                governance_program,
+                realm,
+                governance,
+                proposal,
+                proposal_owner_record,
+                governing_token_mint,
+                max_voter_weight_record.as_ref(),
+            )],
+            InstructionCommand::ExecuteTransaction {
+                governance_program,
+                governance,
+                proposal,
+                option_index,
+                index,
+                instructions,
+            } => vec![execute_transaction(
+                governance_program,
+                governance,
+                proposal,
+                *option_index,
+                *index,
+                instructions,
+            )],
+            InstructionCommand::SetGovernanceConfig {
+                governance_program,
+                governance,
+                config,
+            } => vec![set_governance_config(
+                governance_program,
+                governance,
+                config.into(),
+            )],
+            InstructionCommand::SetRealmConfig {
+                governance_program,
+                realm,
+                realm_authority,
+                payer,
+                council_mint,
+                min_community_weight_to_create_governance,
+                community_mint_max_voter_weight_source,
+                community_token_type,
+                council_token_type,
+                community_voter_weight_addin,
+                max_community_voter_weight_addin,
+                council_voter_weight_addin,
+                max_council_voter_weight_addin,
+                ..
+            } => vec![set_realm_config(
+                governance_program,
+                realm,
+                realm_authority,
+                council_mint.as_ref(),
+                payer.as_ref().unwrap_or(realm_authority),
+                community_voter_weight_addin.as_ref(),
+                max_community_voter_weight_addin.as_ref(),
+                *min_community_weight_to_create_governance,
+                community_mint_max_voter_weight_source.clone(),
+                council_voter_weight_addin.as_ref(),
+                max_council_voter_weight_addin.as_ref(),
+                community_token_type.clone(),
+                council_token_type.clone(),
+            )],
+            InstructionCommand::SetRealmAuthority {
+                governance_program,
+                realm,
+                realm_authority,
+                new_realm_authority,
+                unchecked,
+                remove,
+                ..
+            } => {
+                let action = if *remove {
+                    SetRealmAuthorityAction::Remove
+                } else if *unchecked {
+                    SetRealmAuthorityAction::SetUnchecked
+                } else {
+                    SetRealmAuthorityAction::SetChecked
+                };
+
+                vec![set_realm_authority(
+                    governance_program,
+                    realm,
+                    realm_authority,
+                    new_realm_authority.as_ref(),
+                    action,
+                )]
+            }
+            InstructionCommand::TransferSol {
+                governance_program,
+                governance,
+                recipient,
+                amount,
+            } => vec![transfer_sol(
+                governance_program,
+                governance,
+                recipient,
+                amount.lamports()?,
+            )],
+            InstructionCommand::TransferTokens {
+                governance_program,
+                governance,
+                treasury_owner,
+                mint,
+                recipient,
+                amount,
+                decimals,
+                token_2022,
+            } => vec![transfer_tokens(
+                governance_program,
+                governance,
+                *treasury_owner,
+                if *token_2022 {
+                    &TOKEN_2022_PROGRAM_ID
+                } else {
+                    &TOKEN_PROGRAM_ID
+                },
+                mint,
+                recipient,
+                parse_ui_amount(amount, *decimals)?,
+                *decimals,
+            )],
+        };
+
+        Ok(instructions)
+    }
+
+    /// Returns the spl-governance program instance the instructions are built for,
+    /// if the command builds governance instructions
+    pub fn governance_program(&self) -> Option<&Pubkey> {
+        match self {
+            InstructionCommand::InsertTransaction {
+                governance_program, ..
+            }
+            | InstructionCommand::CreateProposal {
+                governance_program, ..
+            }
+            | InstructionCommand::AddSignatory {
+                governance_program, ..
+            }
+            | InstructionCommand::SignOffProposal {
+                governance_program, ..
+            }
+            | InstructionCommand::CastVote {
+                governance_program, ..
+            }
+            | InstructionCommand::FinalizeVote {
+                governance_program, ..
+            }
+            | InstructionCommand::ExecuteTransaction {
+                governance_program, ..
+            }
+            | InstructionCommand::SetGovernanceConfig {
+                governance_program, ..
+            }
+            | InstructionCommand::SetRealmConfig {
+                governance_program, ..
+            }
+            | InstructionCommand::SetRealmAuthority {
+                governance_program, ..
+            } => Some(governance_program),
+            _ => None,
+        }
+    }
+
+    /// Returns the warnings to be shown before the instructions are used
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = vec![];
+        match self {
+            InstructionCommand::SetUpgradeAuthority {
+                new_authority,
+                checked,
+                ..
+            } => warnings.extend(checked_transfer_warning(
+                *checked,
+                new_authority,
+                "the program unupgradable",
+            )),
+            InstructionCommand::SetBufferAuthority {
+                new_authority,
+                checked,
+                ..
+            } => warnings.extend(checked_transfer_warning(
+                *checked,
+                new_authority,
+                "the buffer unusable",
+            )),
+            InstructionCommand::SetRealmAuthority {
+                unchecked: true, ..
+            } => warnings.push(
+                "the unchecked authority transfer does not verify that the new authority \
+                 is a governance of the realm"
+                    .to_string(),
+            ),
+            _ => {}
+        }
+        if let Some(Err(warning)) = self.check_governance_authority() {
+            warnings.push(warning);
+        }
+        warnings
+    }
+
+    /// Returns the informational notes about the instructions
+    pub fn notes(&self) -> Vec<String> {
+        let mut notes: Vec<_> = self
+            .check_governance_authority()
+            .and_then(Result::ok)
+            .into_iter()
+            .collect();
+        if let InstructionCommand::CreateProposal {
+            governance_program,
+            governance,
+            governing_token_mint,
+            proposal_seed,
+            ..
+        } = self
+        {
+            notes.push(format!(
+                "the proposal address is {}",
+                get_proposal_address(
+                    governance_program,
+                    governance,
+                    governing_token_mint,
+                    proposal_seed
+                )
+            ));
+        }
+        if let InstructionCommand::TransferSol {
+            governance_program,
+            governance,
+            amount,
+            ..
+        } = self
+        {
+            if let Ok(lamports) = amount.lamports() {
+                notes.push(format!(
+                    "transferring {} SOL from the native treasury {}",
+                    format_ui_amount(lamports, SOL_DECIMALS),
+                    get_native_treasury_address(governance_program, governance)
+                ));
+            }
+        }
+        if let InstructionCommand::TransferTokens { recipient, .. } = self {
+            notes.push(format!(
+                "the associated token account of {} must exist before the transfer is executed",
+                recipient
+            ));
+        }
+        notes
+    }
+
+    /// Checks the program authority against the realm, if the command has both
+    fn check_governance_authority(&self) -> Option<Result<String, String>> {
+        match self {
+            InstructionCommand::SetUpgradeAuthority {
+                program,
+                authority,
+                realm,
+                ..
+            }
+            | InstructionCommand::MakeImmutable {
+                program,
+                authority,
+                realm,
+                ..
+            }
+            | InstructionCommand::CloseProgram {
+                program,
+                authority,
+                realm,
+                ..
+            }
+            | InstructionCommand::Upgrade {
+                program,
+                authority,
+                realm,
+                ..
+            } => realm.check_authority(program, authority),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the warning about the choice of the loader authority transfer variant.
+/// A PDA can't co-sign the checked transfer, so it is only advised for wallets
+fn checked_transfer_warning(
+    checked: bool,
+    new_authority: &Pubkey,
+    consequence: &str,
+) -> Option<String> {
+    match (checked, new_authority.is_on_curve()) {
+        (false, true) => Some(format!(
+            "the unchecked authority transfer does not verify the new authority, \
+             a mistyped address makes {}; consider --checked",
+            consequence
+        )),
+        (true, false) => Some(
+            "the new authority is a PDA (e.g. a governance or its treasury) which can't \
+             co-sign the checked transfer when the proposal is executed; omit --checked"
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
+/// Checks the upgrade authority against the ProgramData dump, if one is given
+fn check_program_data_authority(
+    program: &Pubkey,
+    program_data_dump: &Option<PathBuf>,
+    authority: &Pubkey,
+) -> Result<(), Error> {
+    if let Some(program_data_dump) = program_data_dump {
+        let program_data =
+            read_account_dump(program_data_dump, &get_program_data_address(program))?;
+        check_upgrade_authority(&program_data, authority)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::AccountMeta;
+
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(str::to_string).collect()
+    }
+
+    const CLOSE_BUFFER: &str = "close-buffer --buffer 11111111111111111111111111111111 \
+        --recipient 11111111111111111111111111111111 \
+        --authority 11111111111111111111111111111111";
+
+    #[test]
+    fn parse_steps_separated_with_plus() {
+        let steps = parse_bundle_steps(&args(&format!("{} + {}", CLOSE_BUFFER, CLOSE_BUFFER)));
+        assert_eq!(steps.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn reject_trailing_plus() {
+        let err = parse_bundle_steps(&args(&format!("{} +", CLOSE_BUFFER))).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingSubcommand);
+        assert!(err.to_string().contains("empty bundle step"));
+    }
+
+    #[test]
+    fn derive_requires_realm() {
+        let err = Cli::try_parse_from(["generator", "derive"]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let cli = Cli::try_parse_from(["generator", "derive", "--realm-name", "Mango"]).unwrap();
+        assert!(matches!(cli.command, Command::Derive(_)));
+    }
+
+    fn create_proposal(options: &[&str], multi_choice: bool) -> Result<Vec<Instruction>, Error> {
+        let mut step = args(
+            "create-proposal --realm 11111111111111111111111111111111 \
+            --governance 11111111111111111111111111111111 \
+            --proposal-owner-record 11111111111111111111111111111111 \
+            --governance-authority 11111111111111111111111111111111 \
+            --governing-token-mint 11111111111111111111111111111111 \
+            --proposal-seed 11111111111111111111111111111111 --name test",
+        );
+        for option in options {
+            step.extend(["--option".to_string(), option.to_string()]);
+        }
+        if multi_choice {
+            step.push("--multi-choice".to_string());
+        }
+        parse_step(&step).unwrap().instructions()
+    }
+
+    #[test]
+    fn proposal_options_count() {
+        let options = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11"];
+
+        assert!(create_proposal(&[], false).is_ok());
+        assert!(create_proposal(&options[..10], true).is_ok());
+        assert!(create_proposal(&options[..2], true).is_ok());
+        assert!(matches!(
+            create_proposal(&options, false),
+            Err(Error::InvalidArgument(message))
+                if message == "expected from 1 to 10 proposal options, got 11"
+        ));
+        assert!(matches!(
+            create_proposal(&options[..1], true),
+            Err(Error::InvalidArgument(message))
+                if message == "expected from 2 to 10 proposal options with --multi-choice, got 1"
+        ));
+    }
+
+    #[test]
+    fn add_signatory_payer() {
+        let step = args(
+            "add-signatory --governance 11111111111111111111111111111111 \
+            --proposal 11111111111111111111111111111111 \
+            --signatory 11111111111111111111111111111111",
+        );
+        let authority = Pubkey::new_unique();
+        let proposal_owner = [
+            "--governance-authority".to_string(),
+            authority.to_string(),
+            "--token-owner-record".to_string(),
+            Pubkey::new_unique().to_string(),
+        ];
+
+        let err = parse_step(&step).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+        let instructions = parse_step(&[&step[..], &proposal_owner].concat())
+            .unwrap()
+            .instructions()
+            .unwrap();
+        assert_eq!(
+            instructions[0].accounts[3],
+            AccountMeta::new(authority, true)
+        );
+
+        let payer = Pubkey::new_unique();
+        let instructions =
+            parse_step(&[&step[..], &["--payer".to_string(), payer.to_string()]].concat())
+                .unwrap()
+                .instructions()
+                .unwrap();
+        assert_eq!(instructions[0].accounts[3], AccountMeta::new(payer, true));
+    }
+
+    #[test]
+    fn transfer_sol_amount() {
+        let step = args(
+            "transfer-sol --governance 11111111111111111111111111111111 \
+            --recipient 11111111111111111111111111111111",
+        );
+        let transfer = |amount: &str| {
+            parse_step(&[&step[..], &args(amount)].concat()).map(|command| {
+                let instructions = command.instructions().unwrap();
+                u64::from_le_bytes(instructions[0].data[4..12].try_into().unwrap())
+            })
+        };
+
+        assert_eq!(transfer("--sol 1.5").unwrap(), 1_500_000_000);
+        assert_eq!(transfer("--lamports 42").unwrap(), 42);
+        assert_eq!(
+            transfer("").unwrap_err().kind(),
+            ErrorKind::MissingRequiredArgument
+        );
+        assert_eq!(
+            transfer("--sol 1 --lamports 1").unwrap_err().kind(),
+            ErrorKind::ArgumentConflict
+        );
+    }
+
+    #[test]
+    fn reject_help_step() {
+        let err = parse_step(&args("help")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidSubcommand);
+    }
+}
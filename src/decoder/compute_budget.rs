@@ -0,0 +1,86 @@
+use borsh::BorshDeserialize;
+use solana_program::{pubkey, pubkey::Pubkey};
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Address of the compute budget program
+pub const ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// Compute budget instructions, mirrors the borsh layout of the SDK
+#[derive(BorshDeserialize)]
+enum ComputeBudgetInstruction {
+    Unused,
+    RequestHeapFrame(u32),
+    SetComputeUnitLimit(u32),
+    SetComputeUnitPrice(u64),
+    SetLoadedAccountsDataSizeLimit(u32),
+}
+
+/// Decoder of the compute budget program instructions
+pub struct ComputeBudgetDecoder;
+
+impl InstructionDecoder for ComputeBudgetDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let compute_budget_instruction =
+            ComputeBudgetInstruction::try_from_slice(&instruction.data).ok()?;
+        let decoded = |name| DecodedInstruction::new("Compute Budget Program", name, instruction);
+
+        match compute_budget_instruction {
+            ComputeBudgetInstruction::Unused => None,
+            ComputeBudgetInstruction::RequestHeapFrame(bytes) => {
+                Some(decoded("RequestHeapFrame").with_field("bytes", bytes))
+            }
+            ComputeBudgetInstruction::SetComputeUnitLimit(units) => {
+                Some(decoded("SetComputeUnitLimit").with_field("units", units))
+            }
+            ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports) => {
+                Some(decoded("SetComputeUnitPrice").with_field("micro_lamports", micro_lamports))
+            }
+            ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit(bytes) => {
+                Some(decoded("SetLoadedAccountsDataSizeLimit").with_field("bytes", bytes))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::Instruction;
+
+    use super::*;
+
+    #[test]
+    fn decode_set_compute_unit_price() {
+        // SetComputeUnitPrice tag followed by the little-endian price, as built by the SDK
+        let mut data = vec![3];
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        let instruction = Instruction::new_with_bytes(ID, &data, vec![]);
+
+        let decoded = ComputeBudgetDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "SetComputeUnitPrice");
+        assert_eq!(decoded.fields, vec![("micro_lamports", "5000".to_string())]);
+        assert!(decoded.roles().is_empty());
+    }
+
+    #[test]
+    fn decode_set_compute_unit_limit() {
+        let mut data = vec![2];
+        data.extend_from_slice(&200_000u32.to_le_bytes());
+        let instruction = Instruction::new_with_bytes(ID, &data, vec![]);
+
+        let decoded = ComputeBudgetDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "SetComputeUnitLimit");
+        assert_eq!(decoded.fields, vec![("units", "200000".to_string())]);
+    }
+}
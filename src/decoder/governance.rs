@@ -0,0 +1,520 @@
+use borsh::BorshDeserialize;
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    governance::instruction::GovernanceInstruction,
+    InstructionData,
+};
+
+/// Decoder of the spl-governance program instructions
+pub struct GovernanceDecoder;
+
+impl InstructionDecoder for GovernanceDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let governance_instruction =
+            GovernanceInstruction::try_from_slice(&instruction.data).ok()?;
+        let decoded = |name| DecodedInstruction::new("SPL Governance", name, instruction);
+
+        Some(match governance_instruction {
+            GovernanceInstruction::CreateRealm { name, config_args } => decoded("CreateRealm")
+                .with_field("name", name)
+                .with_field("config_args", format!("{:#?}", config_args))
+                .with_roles(&[
+                    "realm",
+                    "realm authority",
+                    "community token mint",
+                    "community token holding",
+                    "payer",
+                    "system program",
+                    "token program",
+                    "rent sysvar",
+                ]),
+            GovernanceInstruction::DepositGoverningTokens { amount } => {
+                decoded("DepositGoverningTokens")
+                    .with_field("amount", amount)
+                    .with_roles(&[
+                        "realm",
+                        "governing token holding",
+                        "governing token source",
+                        "governing token owner",
+                        "governing token source authority",
+                        "token owner record",
+                        "payer",
+                        "system program",
+                        "token program",
+                        "realm config",
+                    ])
+            }
+            GovernanceInstruction::WithdrawGoverningTokens {} => decoded("WithdrawGoverningTokens")
+                .with_roles(&[
+                    "realm",
+                    "governing token holding",
+                    "governing token destination",
+                    "governing token owner",
+                    "token owner record",
+                    "token program",
+                    "realm config",
+                ]),
+            GovernanceInstruction::SetGovernanceDelegate {
+                new_governance_delegate,
+            } => decoded("SetGovernanceDelegate")
+                .with_field(
+                    "new_governance_delegate",
+                    new_governance_delegate.map_or_else(|| "none".to_string(), |d| d.to_string()),
+                )
+                .with_roles(&["governance authority", "token owner record"]),
+            GovernanceInstruction::CreateGovernance { config } => decoded("CreateGovernance")
+                .with_field("config", format!("{:#?}", config))
+                .with_roles(&[
+                    "realm",
+                    "governance",
+                    "governed account",
+                    "token owner record",
+                    "payer",
+                    "system program",
+                    "governance authority",
+                    "realm config",
+                ]),
+            GovernanceInstruction::CreateProgramGovernance {
+                config,
+                transfer_upgrade_authority,
+            } => decoded("CreateProgramGovernance")
+                .with_field("config", format!("{:#?}", config))
+                .with_field("transfer_upgrade_authority", transfer_upgrade_authority)
+                .with_roles(&[
+                    "realm",
+                    "program governance",
+                    "governed program",
+                    "governed programdata",
+                    "program upgrade authority",
+                    "token owner record",
+                    "payer",
+                    "bpf upgradeable loader",
+                    "system program",
+                    "governance authority",
+                    "realm config",
+                ]),
+            GovernanceInstruction::CreateProposal {
+                name,
+                description_link,
+                vote_type,
+                options,
+                use_deny_option,
+                proposal_seed,
+            } => {
+                let mut decoded = decoded("CreateProposal")
+                    .with_field("name", name)
+                    .with_field("description_link", description_link)
+                    .with_field("vote_type", format!("{:?}", vote_type))
+                    .with_field("options", format!("{:?}", options))
+                    .with_field("use_deny_option", use_deny_option)
+                    .with_field("proposal_seed", proposal_seed)
+                    .with_roles(&[
+                        "realm",
+                        "proposal",
+                        "governance",
+                        "proposal owner record",
+                        "governing token mint",
+                        "governance authority",
+                        "payer",
+                        "system program",
+                        "realm config",
+                        "voter weight record",
+                    ]);
+                // The proposal deposit follows the optional voter weight record
+                if decoded.accounts.len() > 9 {
+                    if let Some(account) = decoded.accounts.last_mut() {
+                        account.0 = "proposal deposit";
+                    }
+                }
+                decoded
+            }
+            GovernanceInstruction::AddSignatory { signatory } => decoded("AddSignatory")
+                .with_field("signatory", signatory)
+                .with_roles(&[
+                    "governance",
+                    "proposal",
+                    "signatory record",
+                    "payer",
+                    "system program",
+                    "proposal owner record or required signatory",
+                    "governance authority",
+                ]),
+            GovernanceInstruction::Legacy1 => decoded("Legacy1"),
+            GovernanceInstruction::InsertTransaction {
+                option_index,
+                index,
+                hold_up_time,
+                instructions,
+            } => {
+                let mut decoded = decoded("InsertTransaction")
+                    .with_field("option_index", option_index)
+                    .with_field("index", index)
+                    .with_field("hold_up_time", hold_up_time);
+                for inner_instruction in &instructions {
+                    decoded = decoded.with_field(
+                        "instruction",
+                        format!("\n{}", registry.describe(inner_instruction)),
+                    );
+                }
+                decoded.with_roles(&[
+                    "governance",
+                    "proposal",
+                    "token owner record",
+                    "governance authority",
+                    "proposal transaction",
+                    "payer",
+                    "system program",
+                    "rent sysvar",
+                ])
+            }
+            GovernanceInstruction::RemoveTransaction => decoded("RemoveTransaction").with_roles(&[
+                "proposal",
+                "token owner record",
+                "governance authority",
+                "proposal transaction",
+                "beneficiary",
+            ]),
+            GovernanceInstruction::CancelProposal => decoded("CancelProposal").with_roles(&[
+                "realm",
+                "governance",
+                "proposal",
+                "token owner record",
+                "governance authority",
+            ]),
+            GovernanceInstruction::SignOffProposal => decoded("SignOffProposal").with_roles(&[
+                "realm",
+                "governance",
+                "proposal",
+                "signatory",
+                "signatory record or proposal owner record",
+            ]),
+            GovernanceInstruction::CastVote { vote } => decoded("CastVote")
+                .with_field("vote", format!("{:?}", vote))
+                .with_roles(&[
+                    "realm",
+                    "governance",
+                    "proposal",
+                    "proposal owner record",
+                    "voter token owner record",
+                    "governance authority",
+                    "vote record",
+                    "vote governing token mint",
+                    "payer",
+                    "system program",
+                    "realm config",
+                ]),
+            GovernanceInstruction::FinalizeVote {} => decoded("FinalizeVote").with_roles(&[
+                "realm",
+                "governance",
+                "proposal",
+                "proposal owner record",
+                "governing token mint",
+                "realm config",
+            ]),
+            GovernanceInstruction::RelinquishVote {} => decoded("RelinquishVote").with_roles(&[
+                "realm",
+                "governance",
+                "proposal",
+                "token owner record",
+                "vote record",
+                "governing token mint",
+                "governance authority",
+                "beneficiary",
+            ]),
+            GovernanceInstruction::ExecuteTransaction => {
+                let mut decoded = decoded("ExecuteTransaction").with_roles(&[
+                    "governance",
+                    "proposal",
+                    "proposal transaction",
+                    "instruction program",
+                ]);
+                for account in decoded.accounts.iter_mut().skip(4) {
+                    account.0 = "instruction account";
+                }
+                decoded
+            }
+            GovernanceInstruction::CreateMintGovernance {
+                config,
+                transfer_mint_authorities,
+            } => decoded("CreateMintGovernance")
+                .with_field("config", format!("{:#?}", config))
+                .with_field("transfer_mint_authorities", transfer_mint_authorities)
+                .with_roles(&[
+                    "realm",
+                    "mint governance",
+                    "governed mint",
+                    "mint authority",
+                    "token owner record",
+                    "payer",
+                    "token program",
+                    "system program",
+                    "governance authority",
+                    "realm config",
+                ]),
+            GovernanceInstruction::CreateTokenGovernance {
+                config,
+                transfer_account_authorities,
+            } => decoded("CreateTokenGovernance")
+                .with_field("config", format!("{:#?}", config))
+                .with_field("transfer_account_authorities", transfer_account_authorities)
+                .with_roles(&[
+                    "realm",
+                    "token governance",
+                    "governed token account",
+                    "token account owner",
+                    "token owner record",
+                    "payer",
+                    "token program",
+                    "system program",
+                    "governance authority",
+                    "realm config",
+                ]),
+            GovernanceInstruction::SetGovernanceConfig { config } => decoded("SetGovernanceConfig")
+                .with_field("config", format!("{:#?}", config))
+                .with_roles(&["governance"]),
+            GovernanceInstruction::FlagTransactionError => decoded("FlagTransactionError")
+                .with_roles(&[
+                    "proposal",
+                    "token owner record",
+                    "governance authority",
+                    "proposal transaction",
+                ]),
+            GovernanceInstruction::SetRealmAuthority { action } => decoded("SetRealmAuthority")
+                .with_field("action", format!("{:?}", action))
+                .with_roles(&["realm", "realm authority", "new realm authority"]),
+            GovernanceInstruction::SetRealmConfig { config_args } => {
+                let mut roles = vec!["realm", "realm authority"];
+                if config_args.use_council_mint {
+                    roles.extend(["council token mint", "council token holding"]);
+                }
+                roles.extend(["system program", "realm config"]);
+                let community = &config_args.community_token_config_args;
+                let council = &config_args.council_token_config_args;
+                for (used, role) in [
+                    (
+                        community.use_voter_weight_addin,
+                        "community voter weight addin",
+                    ),
+                    (
+                        community.use_max_voter_weight_addin,
+                        "community max voter weight addin",
+                    ),
+                    (council.use_voter_weight_addin, "council voter weight addin"),
+                    (
+                        council.use_max_voter_weight_addin,
+                        "council max voter weight addin",
+                    ),
+                ] {
+                    if used {
+                        roles.push(role);
+                    }
+                }
+                roles.push("payer");
+
+                decoded("SetRealmConfig")
+                    .with_field("config_args", format!("{:#?}", config_args))
+                    .with_roles(&roles)
+            }
+            GovernanceInstruction::CreateTokenOwnerRecord {} => decoded("CreateTokenOwnerRecord")
+                .with_roles(&[
+                    "realm",
+                    "governing token owner",
+                    "token owner record",
+                    "governing token mint",
+                    "payer",
+                    "system program",
+                ]),
+            GovernanceInstruction::UpdateProgramMetadata {} => decoded("UpdateProgramMetadata")
+                .with_roles(&["program metadata", "payer", "system program"]),
+            GovernanceInstruction::CreateNativeTreasury => decoded("CreateNativeTreasury")
+                .with_roles(&["governance", "native treasury", "payer", "system program"]),
+            GovernanceInstruction::RevokeGoverningTokens { amount } => {
+                decoded("RevokeGoverningTokens")
+                    .with_field("amount", amount)
+                    .with_roles(&[
+                        "realm",
+                        "governing token holding",
+                        "token owner record",
+                        "governing token mint",
+                        "revoke authority",
+                        "realm config",
+                        "token program",
+                    ])
+            }
+            GovernanceInstruction::RefundProposalDeposit {} => decoded("RefundProposalDeposit")
+                .with_roles(&["proposal", "proposal deposit", "proposal deposit payer"]),
+            GovernanceInstruction::CompleteProposal {} => {
+                decoded("CompleteProposal").with_roles(&[
+                    "proposal",
+                    "token owner record",
+                    "complete proposal authority",
+                ])
+            }
+            GovernanceInstruction::AddRequiredSignatory { signatory } => {
+                decoded("AddRequiredSignatory")
+                    .with_field("signatory", signatory)
+                    .with_roles(&[
+                        "governance",
+                        "required signatory",
+                        "payer",
+                        "system program",
+                    ])
+            }
+            GovernanceInstruction::RemoveRequiredSignatory => decoded("RemoveRequiredSignatory")
+                .with_roles(&["governance", "required signatory", "beneficiary"]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+    use crate::governance::{
+        instruction::{cast_vote, set_realm_config},
+        pda::{get_governing_token_holding_address, get_realm_config_address},
+        state::{GoverningTokenType, MintMaxVoterWeightSource, Vote},
+        DEFAULT_GOVERNANCE_PROGRAM_ID,
+    };
+
+    #[test]
+    fn decode_cast_vote() {
+        let voter = Pubkey::new_unique();
+        let instruction = cast_vote(
+            &DEFAULT_GOVERNANCE_PROGRAM_ID,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &voter,
+            &Pubkey::new_unique(),
+            &voter,
+            None,
+            None,
+            Vote::Deny,
+        );
+
+        let decoded = GovernanceDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "CastVote");
+        assert_eq!(decoded.fields, vec![("vote", "Deny".to_string())]);
+        assert_eq!(
+            decoded.roles(),
+            [
+                "realm",
+                "governance",
+                "proposal",
+                "proposal owner record",
+                "voter token owner record",
+                "governance authority",
+                "vote record",
+                "vote governing token mint",
+                "payer",
+                "system program",
+                "realm config",
+            ]
+        );
+    }
+
+    /// Builds SetRealmConfig and decodes it back into the role and pubkey of each account
+    fn set_realm_config_roles(
+        realm: &Pubkey,
+        council_token_mint: Option<&Pubkey>,
+        addins: [Option<&Pubkey>; 4],
+    ) -> Vec<(&'static str, Pubkey)> {
+        let instruction = set_realm_config(
+            &DEFAULT_GOVERNANCE_PROGRAM_ID,
+            realm,
+            &Pubkey::new_from_array([1; 32]),
+            council_token_mint,
+            &Pubkey::new_from_array([2; 32]),
+            addins[0],
+            addins[1],
+            0,
+            MintMaxVoterWeightSource::SupplyFraction(10_000_000_000),
+            addins[2],
+            addins[3],
+            GoverningTokenType::Liquid,
+            GoverningTokenType::Membership,
+        );
+
+        GovernanceDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap()
+            .accounts
+            .into_iter()
+            .map(|(role, account)| (role, account.pubkey))
+            .collect()
+    }
+
+    #[test]
+    fn set_realm_config_roles_without_council_and_addins() {
+        let realm = Pubkey::new_unique();
+
+        assert_eq!(
+            set_realm_config_roles(&realm, None, [None; 4]),
+            vec![
+                ("realm", realm),
+                ("realm authority", Pubkey::new_from_array([1; 32])),
+                ("system program", solana_program::system_program::id()),
+                (
+                    "realm config",
+                    get_realm_config_address(&DEFAULT_GOVERNANCE_PROGRAM_ID, &realm)
+                ),
+                ("payer", Pubkey::new_from_array([2; 32])),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_realm_config_roles_with_council_and_addins() {
+        let realm = Pubkey::new_unique();
+        let council_mint = Pubkey::new_unique();
+        let addins = [(); 4].map(|_| Pubkey::new_unique());
+
+        assert_eq!(
+            set_realm_config_roles(&realm, Some(&council_mint), addins.each_ref().map(Some)),
+            vec![
+                ("realm", realm),
+                ("realm authority", Pubkey::new_from_array([1; 32])),
+                ("council token mint", council_mint),
+                (
+                    "council token holding",
+                    get_governing_token_holding_address(
+                        &DEFAULT_GOVERNANCE_PROGRAM_ID,
+                        &realm,
+                        &council_mint
+                    )
+                ),
+                ("system program", solana_program::system_program::id()),
+                (
+                    "realm config",
+                    get_realm_config_address(&DEFAULT_GOVERNANCE_PROGRAM_ID, &realm)
+                ),
+                ("community voter weight addin", addins[0]),
+                ("community max voter weight addin", addins[1]),
+                ("council voter weight addin", addins[2]),
+                ("council max voter weight addin", addins[3]),
+                ("payer", Pubkey::new_from_array([2; 32])),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_realm_config_roles_with_council_addin_only() {
+        let realm = Pubkey::new_unique();
+        let addin = Pubkey::new_unique();
+
+        let roles = set_realm_config_roles(&realm, None, [None, None, Some(&addin), None]);
+        assert_eq!(roles[4], ("council voter weight addin", addin));
+        assert_eq!(roles[5].0, "payer");
+    }
+}
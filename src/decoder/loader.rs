@@ -0,0 +1,117 @@
+use solana_program::loader_upgradeable_instruction::UpgradeableLoaderInstruction;
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Decoder of the BPF upgradeable loader instructions
+pub struct LoaderDecoder;
+
+impl InstructionDecoder for LoaderDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let loader_instruction: UpgradeableLoaderInstruction =
+            bincode::deserialize(&instruction.data).ok()?;
+        let decoded = |name| DecodedInstruction::new("BPF Upgradeable Loader", name, instruction);
+
+        Some(match loader_instruction {
+            UpgradeableLoaderInstruction::InitializeBuffer => {
+                decoded("InitializeBuffer").with_roles(&["buffer", "authority"])
+            }
+            UpgradeableLoaderInstruction::Write { offset, bytes } => decoded("Write")
+                .with_field("offset", offset)
+                .with_field("bytes", bytes.len())
+                .with_roles(&["buffer", "authority"]),
+            UpgradeableLoaderInstruction::DeployWithMaxDataLen { max_data_len } => {
+                decoded("DeployWithMaxDataLen")
+                    .with_field("max_data_len", max_data_len)
+                    .with_roles(&[
+                        "payer",
+                        "programdata",
+                        "program",
+                        "buffer",
+                        "rent sysvar",
+                        "clock sysvar",
+                        "system program",
+                        "authority",
+                    ])
+            }
+            UpgradeableLoaderInstruction::Upgrade => decoded("Upgrade").with_roles(&[
+                "programdata",
+                "program",
+                "buffer",
+                "spill",
+                "rent sysvar",
+                "clock sysvar",
+                "authority",
+            ]),
+            UpgradeableLoaderInstruction::SetAuthority => {
+                let name = if instruction.accounts.len() < 3 {
+                    "SetAuthority (make immutable)"
+                } else {
+                    "SetAuthority"
+                };
+                decoded(name).with_roles(&["buffer or programdata", "authority", "new authority"])
+            }
+            UpgradeableLoaderInstruction::Close => decoded("Close").with_roles(&[
+                "account to close",
+                "recipient",
+                "authority",
+                "program",
+            ]),
+            UpgradeableLoaderInstruction::ExtendProgram { additional_bytes } => {
+                decoded("ExtendProgram")
+                    .with_field("additional_bytes", additional_bytes)
+                    .with_roles(&["programdata", "program", "system program", "payer"])
+            }
+            UpgradeableLoaderInstruction::SetAuthorityChecked => decoded("SetAuthorityChecked")
+                .with_roles(&["buffer or programdata", "authority", "new authority"]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{bpf_loader_upgradeable, pubkey::Pubkey};
+
+    use super::*;
+
+    #[test]
+    fn decode_extend_program() {
+        let program = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let instruction = bpf_loader_upgradeable::extend_program(&program, Some(&payer), 1024);
+
+        let decoded = LoaderDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "ExtendProgram");
+        assert_eq!(
+            decoded.fields,
+            vec![("additional_bytes", "1024".to_string())]
+        );
+        assert_eq!(
+            decoded.roles(),
+            ["programdata", "program", "system program", "payer"]
+        );
+        assert_eq!(decoded.accounts[3].1.pubkey, payer);
+    }
+
+    #[test]
+    fn decode_make_immutable() {
+        let program = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instruction = bpf_loader_upgradeable::set_upgrade_authority(&program, &authority, None);
+
+        let decoded = LoaderDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "SetAuthority (make immutable)");
+        assert!(decoded.fields.is_empty());
+        assert_eq!(decoded.roles(), ["buffer or programdata", "authority"]);
+    }
+}
@@ -0,0 +1,74 @@
+use solana_program::{pubkey, pubkey::Pubkey};
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Address of the SPL Memo program
+pub const MEMO_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Address of the legacy SPL Memo program
+pub const MEMO_V1_ID: Pubkey = pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+/// Decoder of the SPL Memo program instructions
+pub struct MemoDecoder;
+
+impl InstructionDecoder for MemoDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let memo = std::str::from_utf8(&instruction.data).ok()?;
+        let decoded =
+            DecodedInstruction::new("SPL Memo", "Memo", instruction).with_field("memo", memo);
+
+        // every account passed to the memo program is a required signer
+        Some(DecodedInstruction {
+            accounts: decoded
+                .accounts
+                .into_iter()
+                .map(|(_, account)| ("signer", account))
+                .collect(),
+            ..decoded
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn decode_memo() {
+        let signer = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            MEMO_ID,
+            "upgrade v1.2".as_bytes(),
+            vec![AccountMeta::new_readonly(signer, true)],
+        );
+
+        let decoded = MemoDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.program_name, "SPL Memo");
+        assert_eq!(decoded.name, "Memo");
+        assert_eq!(decoded.fields, vec![("memo", "upgrade v1.2".to_string())]);
+        assert_eq!(decoded.roles(), ["signer"]);
+    }
+
+    #[test]
+    fn decode_invalid_utf8() {
+        let instruction = Instruction::new_with_bytes(MEMO_ID, &[0xff, 0xfe], vec![]);
+
+        assert!(MemoDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .is_none());
+    }
+}
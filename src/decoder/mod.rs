@@ -0,0 +1,214 @@
+mod compute_budget;
+mod governance;
+mod loader;
+mod memo;
+mod stake;
+mod system;
+mod token;
+
+use std::{collections::HashMap, fmt};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    decoder::{
+        compute_budget::ComputeBudgetDecoder, governance::GovernanceDecoder, loader::LoaderDecoder,
+        memo::MemoDecoder, stake::StakeDecoder, system::SystemDecoder, token::TokenDecoder,
+    },
+    error::Error,
+    governance::DEFAULT_GOVERNANCE_PROGRAM_ID,
+    token::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    AccountMetaData, InstructionData,
+};
+
+/// Human-readable description of an instruction of a known program
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    /// Pubkey of the program executing the instruction
+    pub program_id: Pubkey,
+    /// Name of the program executing the instruction
+    pub program_name: &'static str,
+    /// Name of the instruction
+    pub name: String,
+    /// Named arguments of the instruction
+    pub fields: Vec<(&'static str, String)>,
+    /// Accounts of the instruction labelled by their role
+    pub accounts: Vec<(&'static str, AccountMetaData)>,
+    /// Raw instruction data
+    pub data: Vec<u8>,
+}
+
+impl DecodedInstruction {
+    fn new(program_name: &'static str, name: &str, instruction: &InstructionData) -> Self {
+        DecodedInstruction {
+            program_id: instruction.program_id,
+            program_name,
+            name: name.to_string(),
+            fields: vec![],
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|a| ("unknown", a.clone()))
+                .collect(),
+            data: instruction.data.clone(),
+        }
+    }
+
+    fn with_field(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.fields.push((name, value.to_string()));
+        self
+    }
+
+    /// Labels the accounts in order, the accounts left over keep the `unknown` role
+    fn with_roles(mut self, roles: &[&'static str]) -> Self {
+        for (account, role) in self.accounts.iter_mut().zip(roles) {
+            account.0 = role;
+        }
+        self
+    }
+
+    /// Roles of the accounts in order
+    #[cfg(test)]
+    fn roles(&self) -> Vec<&'static str> {
+        self.accounts.iter().map(|(role, _)| *role).collect()
+    }
+}
+
+impl fmt::Display for DecodedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Program: {} ({})", self.program_id, self.program_name)?;
+        writeln!(f, "Instruction: {}", self.name)?;
+        for (name, value) in &self.fields {
+            // nested instructions start on a new line and are indented under their field
+            let separator = if value.starts_with('\n') { "" } else { " " };
+            writeln!(
+                f,
+                "  {}:{}{}",
+                name,
+                separator,
+                value.replace('\n', "\n    ")
+            )?;
+        }
+        write!(f, "Accounts:")?;
+        for (index, (role, account)) in self.accounts.iter().enumerate() {
+            write!(
+                f,
+                "\n  {:>2}: {} ({}) signer: {}, writable: {}",
+                index, account.pubkey, role, account.is_signer, account.is_writable
+            )?;
+        }
+        write!(f, "\nData ({} bytes): ", self.data.len())?;
+        for byte in &self.data {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decoder of the instructions of a single program
+pub trait InstructionDecoder {
+    /// Decodes the instruction, returns `None` if its data is not recognised.
+    /// The registry is passed to decode instructions nested in the instruction data
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction>;
+}
+
+/// Instruction decoders keyed by the program id they decode
+pub struct DecoderRegistry {
+    decoders: HashMap<Pubkey, Box<dyn InstructionDecoder>>,
+}
+
+impl DecoderRegistry {
+    /// Creates a registry without any decoders
+    pub fn new() -> Self {
+        DecoderRegistry {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Registers the decoder for the program, replacing the previous one
+    pub fn register(&mut self, program_id: Pubkey, decoder: Box<dyn InstructionDecoder>) {
+        self.decoders.insert(program_id, decoder);
+    }
+
+    /// Registers the spl-governance decoder for a custom governance program instance
+    pub fn register_governance(&mut self, program_id: Pubkey) {
+        self.register(program_id, Box::new(GovernanceDecoder));
+    }
+
+    /// Decodes the instruction, failing for programs without a registered decoder.
+    /// Returns `None` if the decoder does not recognise the instruction data
+    pub fn decode(
+        &self,
+        instruction: &InstructionData,
+    ) -> Result<Option<DecodedInstruction>, Error> {
+        let decoder = self
+            .decoders
+            .get(&instruction.program_id)
+            .ok_or(Error::UnknownProgram(instruction.program_id))?;
+
+        Ok(decoder.decode(instruction, self))
+    }
+
+    /// Describes the instruction, falling back to raw data for unknown programs
+    pub fn describe(&self, instruction: &InstructionData) -> String {
+        match self.decode(instruction) {
+            Ok(Some(decoded)) => decoded.to_string(),
+            _ => instruction.to_string(),
+        }
+    }
+}
+
+impl Default for DecoderRegistry {
+    /// Creates a registry with the decoders of all the known programs
+    fn default() -> Self {
+        let mut registry = DecoderRegistry::new();
+        registry.register(
+            solana_program::system_program::id(),
+            Box::new(SystemDecoder),
+        );
+        registry.register(
+            TOKEN_PROGRAM_ID,
+            Box::new(TokenDecoder {
+                program_name: "SPL Token",
+            }),
+        );
+        registry.register(
+            TOKEN_2022_PROGRAM_ID,
+            Box::new(TokenDecoder {
+                program_name: "SPL Token-2022",
+            }),
+        );
+        registry.register(solana_program::stake::program::id(), Box::new(StakeDecoder));
+        registry.register(compute_budget::ID, Box::new(ComputeBudgetDecoder));
+        registry.register(memo::MEMO_V1_ID, Box::new(MemoDecoder));
+        registry.register(memo::MEMO_ID, Box::new(MemoDecoder));
+        registry.register_governance(DEFAULT_GOVERNANCE_PROGRAM_ID);
+        registry.register(
+            solana_program::bpf_loader_upgradeable::id(),
+            Box::new(LoaderDecoder),
+        );
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_instruction;
+
+    use super::*;
+
+    #[test]
+    fn display_decoded_with_data() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from, &to, 1).into();
+
+        let described = DecoderRegistry::default().describe(&instruction);
+        assert!(described.contains("Instruction: Transfer\n  lamports: 1\n"));
+        assert!(described.ends_with("\nData (12 bytes): 020000000100000000000000"));
+    }
+}
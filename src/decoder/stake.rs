@@ -0,0 +1,162 @@
+use solana_program::stake::instruction::StakeInstruction;
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Decoder of the stake program instructions
+pub struct StakeDecoder;
+
+impl InstructionDecoder for StakeDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let stake_instruction: StakeInstruction = bincode::deserialize(&instruction.data).ok()?;
+        let decoded = |name| DecodedInstruction::new("Stake Program", name, instruction);
+
+        Some(match stake_instruction {
+            StakeInstruction::Initialize(authorized, lockup) => decoded("Initialize")
+                .with_field("staker", authorized.staker)
+                .with_field("withdrawer", authorized.withdrawer)
+                .with_field("lockup", format!("{:?}", lockup))
+                .with_roles(&["stake account", "rent sysvar"]),
+            StakeInstruction::Authorize(new_authority, stake_authorize) => decoded("Authorize")
+                .with_field("new_authority", new_authority)
+                .with_field("authority_type", format!("{:?}", stake_authorize))
+                .with_roles(&["stake account", "clock sysvar", "authority", "custodian"]),
+            StakeInstruction::DelegateStake => decoded("DelegateStake").with_roles(&[
+                "stake account",
+                "vote account",
+                "clock sysvar",
+                "stake history sysvar",
+                "stake config",
+                "stake authority",
+            ]),
+            StakeInstruction::Split(lamports) => decoded("Split")
+                .with_field("lamports", lamports)
+                .with_roles(&["stake account", "split stake account", "stake authority"]),
+            StakeInstruction::Withdraw(lamports) => decoded("Withdraw")
+                .with_field("lamports", lamports)
+                .with_roles(&[
+                    "stake account",
+                    "recipient",
+                    "clock sysvar",
+                    "stake history sysvar",
+                    "withdraw authority",
+                    "custodian",
+                ]),
+            StakeInstruction::Deactivate => decoded("Deactivate").with_roles(&[
+                "stake account",
+                "clock sysvar",
+                "stake authority",
+            ]),
+            StakeInstruction::SetLockup(lockup) => decoded("SetLockup")
+                .with_field("lockup", format!("{:?}", lockup))
+                .with_roles(&["stake account", "lockup or withdraw authority"]),
+            StakeInstruction::Merge => decoded("Merge").with_roles(&[
+                "destination stake account",
+                "source stake account",
+                "clock sysvar",
+                "stake history sysvar",
+                "stake authority",
+            ]),
+            StakeInstruction::AuthorizeWithSeed(args) => decoded("AuthorizeWithSeed")
+                .with_field("new_authority", args.new_authorized_pubkey)
+                .with_field("authority_type", format!("{:?}", args.stake_authorize))
+                .with_field("authority_seed", args.authority_seed)
+                .with_field("authority_owner", args.authority_owner)
+                .with_roles(&["stake account", "base account", "clock sysvar", "custodian"]),
+            StakeInstruction::InitializeChecked => decoded("InitializeChecked").with_roles(&[
+                "stake account",
+                "rent sysvar",
+                "stake authority",
+                "withdraw authority",
+            ]),
+            StakeInstruction::AuthorizeChecked(stake_authorize) => decoded("AuthorizeChecked")
+                .with_field("authority_type", format!("{:?}", stake_authorize))
+                .with_roles(&[
+                    "stake account",
+                    "clock sysvar",
+                    "authority",
+                    "new authority",
+                    "custodian",
+                ]),
+            StakeInstruction::AuthorizeCheckedWithSeed(args) => decoded("AuthorizeCheckedWithSeed")
+                .with_field("authority_type", format!("{:?}", args.stake_authorize))
+                .with_field("authority_seed", args.authority_seed)
+                .with_field("authority_owner", args.authority_owner)
+                .with_roles(&[
+                    "stake account",
+                    "base account",
+                    "clock sysvar",
+                    "new authority",
+                    "custodian",
+                ]),
+            StakeInstruction::SetLockupChecked(lockup) => decoded("SetLockupChecked")
+                .with_field("lockup", format!("{:?}", lockup))
+                .with_roles(&[
+                    "stake account",
+                    "lockup or withdraw authority",
+                    "new lockup authority",
+                ]),
+            StakeInstruction::GetMinimumDelegation => decoded("GetMinimumDelegation"),
+            StakeInstruction::DeactivateDelinquent => {
+                decoded("DeactivateDelinquent").with_roles(&[
+                    "stake account",
+                    "delinquent vote account",
+                    "reference vote account",
+                ])
+            }
+            #[allow(deprecated)]
+            StakeInstruction::Redelegate => decoded("Redelegate").with_roles(&[
+                "stake account",
+                "uninitialized stake account",
+                "vote account",
+                "stake config",
+                "stake authority",
+            ]),
+            StakeInstruction::MoveStake(lamports) => decoded("MoveStake")
+                .with_field("lamports", lamports)
+                .with_roles(&[
+                    "source stake account",
+                    "destination stake account",
+                    "stake authority",
+                ]),
+            StakeInstruction::MoveLamports(lamports) => decoded("MoveLamports")
+                .with_field("lamports", lamports)
+                .with_roles(&[
+                    "source stake account",
+                    "destination stake account",
+                    "stake authority",
+                ]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, stake::instruction as stake_instruction};
+
+    use super::*;
+
+    #[test]
+    fn decode_deactivate() {
+        let stake = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let instruction = stake_instruction::deactivate_stake(&stake, &authority);
+
+        let decoded = StakeDecoder
+            .decode(&instruction.into(), &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.name, "Deactivate");
+        assert!(decoded.fields.is_empty());
+        assert_eq!(
+            decoded.roles(),
+            ["stake account", "clock sysvar", "stake authority"]
+        );
+        assert_eq!(decoded.accounts[2].1.pubkey, authority);
+    }
+}
@@ -0,0 +1,129 @@
+use solana_program::system_instruction::SystemInstruction;
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Decoder of the system program instructions
+pub struct SystemDecoder;
+
+impl InstructionDecoder for SystemDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let system_instruction: SystemInstruction = bincode::deserialize(&instruction.data).ok()?;
+        let decoded = |name| DecodedInstruction::new("System Program", name, instruction);
+
+        Some(match system_instruction {
+            SystemInstruction::CreateAccount {
+                lamports,
+                space,
+                owner,
+            } => decoded("CreateAccount")
+                .with_field("lamports", lamports)
+                .with_field("space", space)
+                .with_field("owner", owner)
+                .with_roles(&["funding account", "new account"]),
+            SystemInstruction::Assign { owner } => decoded("Assign")
+                .with_field("owner", owner)
+                .with_roles(&["assigned account"]),
+            SystemInstruction::Transfer { lamports } => decoded("Transfer")
+                .with_field("lamports", lamports)
+                .with_roles(&["funding account", "recipient"]),
+            SystemInstruction::CreateAccountWithSeed {
+                base,
+                seed,
+                lamports,
+                space,
+                owner,
+            } => decoded("CreateAccountWithSeed")
+                .with_field("base", base)
+                .with_field("seed", seed)
+                .with_field("lamports", lamports)
+                .with_field("space", space)
+                .with_field("owner", owner)
+                .with_roles(&["funding account", "created account", "base account"]),
+            SystemInstruction::AdvanceNonceAccount => decoded("AdvanceNonceAccount").with_roles(&[
+                "nonce account",
+                "recent blockhashes sysvar",
+                "nonce authority",
+            ]),
+            SystemInstruction::WithdrawNonceAccount(lamports) => decoded("WithdrawNonceAccount")
+                .with_field("lamports", lamports)
+                .with_roles(&[
+                    "nonce account",
+                    "recipient",
+                    "recent blockhashes sysvar",
+                    "rent sysvar",
+                    "nonce authority",
+                ]),
+            SystemInstruction::InitializeNonceAccount(authority) => {
+                decoded("InitializeNonceAccount")
+                    .with_field("authority", authority)
+                    .with_roles(&["nonce account", "recent blockhashes sysvar", "rent sysvar"])
+            }
+            SystemInstruction::AuthorizeNonceAccount(new_authority) => {
+                decoded("AuthorizeNonceAccount")
+                    .with_field("new_authority", new_authority)
+                    .with_roles(&["nonce account", "nonce authority"])
+            }
+            SystemInstruction::Allocate { space } => decoded("Allocate")
+                .with_field("space", space)
+                .with_roles(&["allocated account"]),
+            SystemInstruction::AllocateWithSeed {
+                base,
+                seed,
+                space,
+                owner,
+            } => decoded("AllocateWithSeed")
+                .with_field("base", base)
+                .with_field("seed", seed)
+                .with_field("space", space)
+                .with_field("owner", owner)
+                .with_roles(&["allocated account", "base account"]),
+            SystemInstruction::AssignWithSeed { base, seed, owner } => decoded("AssignWithSeed")
+                .with_field("base", base)
+                .with_field("seed", seed)
+                .with_field("owner", owner)
+                .with_roles(&["assigned account", "base account"]),
+            SystemInstruction::TransferWithSeed {
+                lamports,
+                from_seed,
+                from_owner,
+            } => decoded("TransferWithSeed")
+                .with_field("lamports", lamports)
+                .with_field("from_seed", from_seed)
+                .with_field("from_owner", from_owner)
+                .with_roles(&["funding account", "base account", "recipient"]),
+            SystemInstruction::UpgradeNonceAccount => {
+                decoded("UpgradeNonceAccount").with_roles(&["nonce account"])
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::{pubkey::Pubkey, system_instruction};
+
+    use super::*;
+
+    #[test]
+    fn decode_transfer() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let instruction = system_instruction::transfer(&from, &to, 42).into();
+
+        let decoded = SystemDecoder
+            .decode(&instruction, &DecoderRegistry::new())
+            .unwrap();
+        assert_eq!(decoded.program_name, "System Program");
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.fields, vec![("lamports", "42".to_string())]);
+        assert_eq!(decoded.roles(), ["funding account", "recipient"]);
+        assert_eq!(decoded.accounts[1].1.pubkey, to);
+    }
+}
@@ -0,0 +1,280 @@
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    decoder::{DecodedInstruction, DecoderRegistry, InstructionDecoder},
+    InstructionData,
+};
+
+/// Instructions shared by SPL Token and Token-2022. The packed layout of the
+/// token program matches borsh for these variants, `COption<Pubkey>` included
+#[derive(BorshDeserialize)]
+enum TokenInstruction {
+    InitializeMint {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
+    InitializeAccount,
+    InitializeMultisig {
+        m: u8,
+    },
+    Transfer {
+        amount: u64,
+    },
+    Approve {
+        amount: u64,
+    },
+    Revoke,
+    SetAuthority {
+        authority_type: u8,
+        new_authority: Option<Pubkey>,
+    },
+    MintTo {
+        amount: u64,
+    },
+    Burn {
+        amount: u64,
+    },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    ApproveChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    MintToChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    BurnChecked {
+        amount: u64,
+        decimals: u8,
+    },
+    InitializeAccount2 {
+        owner: Pubkey,
+    },
+    SyncNative,
+    InitializeAccount3 {
+        owner: Pubkey,
+    },
+    InitializeMultisig2 {
+        m: u8,
+    },
+    InitializeMint2 {
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    },
+    GetAccountDataSize,
+    InitializeImmutableOwner,
+    AmountToUiAmount {
+        amount: u64,
+    },
+}
+
+/// Tag of the `UiAmountToAmount` instruction whose argument is an unprefixed string
+const UI_AMOUNT_TO_AMOUNT: u8 = 24;
+
+fn authority_type_name(authority_type: u8) -> String {
+    match authority_type {
+        0 => "MintTokens".to_string(),
+        1 => "FreezeAccount".to_string(),
+        2 => "AccountOwner".to_string(),
+        3 => "CloseAccount".to_string(),
+        other => format!("Token-2022 authority type {}", other),
+    }
+}
+
+fn option_to_string(pubkey: Option<Pubkey>) -> String {
+    pubkey.map_or_else(|| "none".to_string(), |pubkey| pubkey.to_string())
+}
+
+/// Decoder of the SPL Token and Token-2022 program instructions
+pub struct TokenDecoder {
+    pub program_name: &'static str,
+}
+
+impl InstructionDecoder for TokenDecoder {
+    fn decode(
+        &self,
+        instruction: &InstructionData,
+        _registry: &DecoderRegistry,
+    ) -> Option<DecodedInstruction> {
+        let decoded = |name| DecodedInstruction::new(self.program_name, name, instruction);
+
+        let token_instruction = match TokenInstruction::try_from_slice(&instruction.data) {
+            Ok(token_instruction) => token_instruction,
+            Err(_) => {
+                let (&tag, rest) = instruction.data.split_first()?;
+                return if tag == UI_AMOUNT_TO_AMOUNT {
+                    Some(
+                        decoded("UiAmountToAmount")
+                            .with_field("ui_amount", std::str::from_utf8(rest).ok()?)
+                            .with_roles(&["mint"]),
+                    )
+                } else {
+                    None
+                };
+            }
+        };
+
+        Some(match token_instruction {
+            TokenInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => decoded("InitializeMint")
+                .with_field("decimals", decimals)
+                .with_field("mint_authority", mint_authority)
+                .with_field("freeze_authority", option_to_string(freeze_authority))
+                .with_roles(&["mint", "rent sysvar"]),
+            TokenInstruction::InitializeAccount => decoded("InitializeAccount").with_roles(&[
+                "token account",
+                "mint",
+                "owner",
+                "rent sysvar",
+            ]),
+            TokenInstruction::InitializeMultisig { m } => decoded("InitializeMultisig")
+                .with_field("m", m)
+                .with_roles(&["multisig", "rent sysvar"]),
+            TokenInstruction::Transfer { amount } => decoded("Transfer")
+                .with_field("amount", amount)
+                .with_roles(&["source", "destination", "authority"]),
+            TokenInstruction::Approve { amount } => decoded("Approve")
+                .with_field("amount", amount)
+                .with_roles(&["source", "delegate", "owner"]),
+            TokenInstruction::Revoke => decoded("Revoke").with_roles(&["source", "owner"]),
+            TokenInstruction::SetAuthority {
+                authority_type,
+                new_authority,
+            } => decoded("SetAuthority")
+                .with_field("authority_type", authority_type_name(authority_type))
+                .with_field("new_authority", option_to_string(new_authority))
+                .with_roles(&["mint or token account", "current authority"]),
+            TokenInstruction::MintTo { amount } => decoded("MintTo")
+                .with_field("amount", amount)
+                .with_roles(&["mint", "destination", "mint authority"]),
+            TokenInstruction::Burn { amount } => decoded("Burn")
+                .with_field("amount", amount)
+                .with_roles(&["token account", "mint", "authority"]),
+            TokenInstruction::CloseAccount => {
+                decoded("CloseAccount").with_roles(&["token account", "destination", "owner"])
+            }
+            TokenInstruction::FreezeAccount => {
+                decoded("FreezeAccount").with_roles(&["token account", "mint", "freeze authority"])
+            }
+            TokenInstruction::ThawAccount => {
+                decoded("ThawAccount").with_roles(&["token account", "mint", "freeze authority"])
+            }
+            TokenInstruction::TransferChecked { amount, decimals } => decoded("TransferChecked")
+                .with_field("amount", amount)
+                .with_field("decimals", decimals)
+                .with_roles(&["source", "mint", "destination", "authority"]),
+            TokenInstruction::ApproveChecked { amount, decimals } => decoded("ApproveChecked")
+                .with_field("amount", amount)
+                .with_field("decimals", decimals)
+                .with_roles(&["source", "mint", "delegate", "owner"]),
+            TokenInstruction::MintToChecked { amount, decimals } => decoded("MintToChecked")
+                .with_field("amount", amount)
+                .with_field("decimals", decimals)
+                .with_roles(&["mint", "destination", "mint authority"]),
+            TokenInstruction::BurnChecked { amount, decimals } => decoded("BurnChecked")
+                .with_field("amount", amount)
+                .with_field("decimals", decimals)
+                .with_roles(&["token account", "mint", "authority"]),
+            TokenInstruction::InitializeAccount2 { owner } => decoded("InitializeAccount2")
+                .with_field("owner", owner)
+                .with_roles(&["token account", "mint", "rent sysvar"]),
+            TokenInstruction::SyncNative => decoded("SyncNative").with_roles(&["token account"]),
+            TokenInstruction::InitializeAccount3 { owner } => decoded("InitializeAccount3")
+                .with_field("owner", owner)
+                .with_roles(&["token account", "mint"]),
+            TokenInstruction::InitializeMultisig2 { m } => decoded("InitializeMultisig2")
+                .with_field("m", m)
+                .with_roles(&["multisig"]),
+            TokenInstruction::InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => decoded("InitializeMint2")
+                .with_field("decimals", decimals)
+                .with_field("mint_authority", mint_authority)
+                .with_field("freeze_authority", option_to_string(freeze_authority))
+                .with_roles(&["mint"]),
+            TokenInstruction::GetAccountDataSize => {
+                decoded("GetAccountDataSize").with_roles(&["mint"])
+            }
+            TokenInstruction::InitializeImmutableOwner => {
+                decoded("InitializeImmutableOwner").with_roles(&["token account"])
+            }
+            TokenInstruction::AmountToUiAmount { amount } => decoded("AmountToUiAmount")
+                .with_field("amount", amount)
+                .with_roles(&["mint"]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::instruction::{AccountMeta, Instruction};
+
+    use super::*;
+    use crate::token::TOKEN_PROGRAM_ID;
+
+    #[test]
+    fn decode_set_authority() {
+        // SetAuthority(AccountOwner, Some(new_owner)) in the packed layout of the token program
+        let new_owner = Pubkey::new_unique();
+        let mut data = vec![6, 2, 1];
+        data.extend_from_slice(new_owner.as_ref());
+        let instruction = Instruction::new_with_bytes(
+            TOKEN_PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), true),
+            ],
+        );
+
+        let decoded = TokenDecoder {
+            program_name: "SPL Token",
+        }
+        .decode(&instruction.into(), &DecoderRegistry::new())
+        .unwrap();
+        assert_eq!(decoded.name, "SetAuthority");
+        assert_eq!(
+            decoded.fields,
+            vec![
+                ("authority_type", "AccountOwner".to_string()),
+                ("new_authority", new_owner.to_string()),
+            ]
+        );
+        assert_eq!(
+            decoded.roles(),
+            ["mint or token account", "current authority"]
+        );
+    }
+
+    #[test]
+    fn decode_ui_amount_to_amount() {
+        let instruction = Instruction::new_with_bytes(
+            TOKEN_PROGRAM_ID,
+            b"\x181.5",
+            vec![AccountMeta::new_readonly(Pubkey::new_unique(), false)],
+        );
+
+        let decoded = TokenDecoder {
+            program_name: "SPL Token",
+        }
+        .decode(&instruction.into(), &DecoderRegistry::new())
+        .unwrap();
+        assert_eq!(decoded.name, "UiAmountToAmount");
+        assert_eq!(decoded.fields, vec![("ui_amount", "1.5".to_string())]);
+        assert_eq!(decoded.roles(), ["mint"]);
+    }
+}
@@ -0,0 +1,118 @@
+use std::{io, path::PathBuf};
+
+use solana_program::pubkey::Pubkey;
+use thiserror::Error;
+
+/// Process exit codes of the error categories, stable for scripting
+pub mod exit_code {
+    /// A pubkey argument could not be parsed
+    pub const INVALID_PUBKEY: i32 = 3;
+    /// An encoded instruction is not valid base64
+    pub const BASE64: i32 = 4;
+    /// Bytes could not be decoded with borsh
+    pub const BORSH: i32 = 5;
+    /// No decoder is known for the instruction program
+    pub const UNKNOWN_PROGRAM: i32 = 6;
+    /// A local file could not be read or has unexpected content
+    pub const INPUT: i32 = 7;
+    /// A pre-flight check against a local account snapshot failed
+    pub const PREFLIGHT: i32 = 8;
+    /// An amount argument could not be parsed
+    pub const INVALID_AMOUNT: i32 = 9;
+    /// An argument is out of the range supported by the program
+    pub const INVALID_ARGUMENT: i32 = 10;
+}
+
+/// Errors of the instruction generator
+#[derive(Debug, Error)]
+pub enum Error {
+    /// String is not a valid base58 pubkey
+    #[error("invalid pubkey `{0}`")]
+    InvalidPubkey(String),
+
+    /// Encoded instruction is not valid base64
+    #[error("invalid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    /// Bytes do not match the borsh layout of the expected type
+    #[error("invalid borsh data: {0}")]
+    Borsh(io::Error),
+
+    /// Bytes were left over after borsh decoding
+    #[error("{0} unexpected trailing bytes after borsh data")]
+    TrailingBytes(usize),
+
+    /// Instruction program has no registered decoder
+    #[error("unknown program {0}")]
+    UnknownProgram(Pubkey),
+
+    /// Local file could not be read
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// Local account dump has unexpected content
+    #[error("invalid account data: {0}")]
+    InvalidAccountData(String),
+
+    /// Upgrade authority of a ProgramData snapshot differs from the supplied one
+    #[error(
+        "upgrade authority mismatch: expected {expected}, ProgramData has {}",
+        actual.map_or_else(|| "none (immutable)".to_string(), |a| a.to_string())
+    )]
+    AuthorityMismatch {
+        expected: Pubkey,
+        actual: Option<Pubkey>,
+    },
+
+    /// ProgramData has to be extended but nobody funds the rent of the extra bytes
+    #[error("ProgramData must be extended by {0} bytes, a payer is required to fund their rent")]
+    MissingExtendPayer(u32),
+
+    /// ProgramData has to grow more than a proposal transaction can extend it by
+    #[error(
+        "ProgramData must be extended by {0} bytes, more than the {1} bytes allowed per \
+         instruction executed by governance; extend it in several transactions first"
+    )]
+    ExtensionTooLarge(usize, usize),
+
+    /// Address book could not be parsed
+    #[error("invalid address book: {0}")]
+    InvalidAddressBook(String),
+
+    /// Proposal manifest could not be parsed
+    #[error("invalid manifest: {0}")]
+    InvalidManifest(String),
+
+    /// String is not a valid amount of the token
+    #[error("invalid amount `{0}`")]
+    InvalidAmount(String),
+
+    /// Argument is out of the range supported by the program
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+}
+
+impl Error {
+    /// Returns the process exit code of the error category
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::InvalidPubkey(_) => exit_code::INVALID_PUBKEY,
+            Error::Base64(_) => exit_code::BASE64,
+            Error::Borsh(_) | Error::TrailingBytes(_) => exit_code::BORSH,
+            Error::UnknownProgram(_) => exit_code::UNKNOWN_PROGRAM,
+            Error::Io { .. }
+            | Error::InvalidAccountData(_)
+            | Error::InvalidAddressBook(_)
+            | Error::InvalidManifest(_) => exit_code::INPUT,
+            Error::AuthorityMismatch { .. }
+            | Error::MissingExtendPayer(_)
+            | Error::ExtensionTooLarge(..) => exit_code::PREFLIGHT,
+            Error::InvalidAmount(_) => exit_code::INVALID_AMOUNT,
+            Error::InvalidArgument(_) => exit_code::INVALID_ARGUMENT,
+        }
+    }
+}
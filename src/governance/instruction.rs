@@ -0,0 +1,1048 @@
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program, sysvar,
+};
+
+use crate::{
+    governance::{
+        pda::{
+            get_governing_token_holding_address, get_native_treasury_address, get_proposal_address,
+            get_proposal_deposit_address, get_proposal_transaction_address,
+            get_realm_config_address, get_required_signatory_address, get_signatory_record_address,
+            get_vote_record_address,
+        },
+        state::{
+            GovernanceConfig, GoverningTokenConfigArgs, GoverningTokenType,
+            MintMaxVoterWeightSource, RealmConfigArgs, SetRealmAuthorityAction, Vote, VoteType,
+        },
+    },
+    InstructionData,
+};
+
+/// Instructions supported by the spl-governance program
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+#[allow(clippy::large_enum_variant)]
+pub enum GovernanceInstruction {
+    /// Creates Governance Realm account which aggregates governances for a given community mint
+    CreateRealm {
+        /// UTF-8 encoded Governance Realm name
+        name: String,
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+    /// Deposits governing tokens (Community or Council) to Governance Realm
+    DepositGoverningTokens {
+        /// The amount to deposit into the realm
+        amount: u64,
+    },
+    /// Withdraws governing tokens (Community or Council) from Governance Realm
+    WithdrawGoverningTokens {},
+    /// Sets Governance Delegate for the given Realm and Governing Token Mint
+    SetGovernanceDelegate {
+        /// New Governance Delegate
+        new_governance_delegate: Option<Pubkey>,
+    },
+    /// Creates Governance account which can be used to govern any arbitrary account
+    CreateGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+    },
+    /// Creates Program Governance account which governs an upgradable program (legacy)
+    CreateProgramGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+        /// Indicates whether the upgrade authority should be transferred to the governance
+        transfer_upgrade_authority: bool,
+    },
+    /// Creates Proposal account for Transactions which will be executed at some point in the future
+    CreateProposal {
+        /// UTF-8 encoded name of the proposal
+        name: String,
+        /// Link to a gist explaining the proposal
+        description_link: String,
+        /// Proposal vote type
+        vote_type: VoteType,
+        /// Proposal options
+        options: Vec<String>,
+        /// Indicates whether the proposal has the deny option
+        use_deny_option: bool,
+        /// Unique seed for the Proposal PDA
+        proposal_seed: Pubkey,
+    },
+    /// Adds a signatory to the Proposal which means this Proposal can't leave Draft state until yet another Signatory signs
+    AddSignatory {
+        /// Signatory to add to the Proposal
+        signatory: Pubkey,
+    },
+    /// Formerly RemoveSignatory, exists for backwards-compatibility
+    Legacy1,
+    /// Inserts Transaction with a set of instructions for the Proposal at the given index position
+    InsertTransaction {
+        /// The index of the option the transaction is for
+        option_index: u8,
+        /// Transaction index to be inserted at
+        index: u16,
+        /// Waiting time (in seconds) between vote period ending and this being eligible for execution
+        hold_up_time: u32,
+        /// Instructions Data
+        instructions: Vec<InstructionData>,
+    },
+    /// Removes Transaction from the Proposal
+    RemoveTransaction,
+    /// Cancels Proposal by changing its state to Canceled
+    CancelProposal,
+    /// Signs off Proposal indicating the Signatory approves the Proposal
+    SignOffProposal,
+    /// Uses your voter weight (deposited Community or Council tokens) to cast a vote on a Proposal
+    CastVote {
+        /// User's vote
+        vote: Vote,
+    },
+    /// Finalizes vote in case the Vote was not automatically tipped within max_voting_time period
+    FinalizeVote {},
+    /// Relinquish Vote removes voter weight from a Proposal and removes it from voter's active votes
+    RelinquishVote {},
+    /// Executes a Transaction in the Proposal
+    ExecuteTransaction,
+    /// Creates Mint Governance account which governs a mint (legacy)
+    CreateMintGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+        /// Indicates whether the mint authorities should be transferred to the governance
+        transfer_mint_authorities: bool,
+    },
+    /// Creates Token Governance account which governs a token account (legacy)
+    CreateTokenGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+        /// Indicates whether the token account authorities should be transferred to the governance
+        transfer_account_authorities: bool,
+    },
+    /// Sets GovernanceConfig for a Governance, can only be invoked by the Governance itself
+    SetGovernanceConfig {
+        /// New governance config
+        config: GovernanceConfig,
+    },
+    /// Flags a transaction and its parent Proposal with error status
+    FlagTransactionError,
+    /// Sets new Realm authority
+    SetRealmAuthority {
+        /// Set action (SetUnchecked, SetChecked, Remove)
+        action: SetRealmAuthorityAction,
+    },
+    /// Sets realm config
+    SetRealmConfig {
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+    /// Creates TokenOwnerRecord with 0 deposit amount
+    CreateTokenOwnerRecord {},
+    /// Updates ProgramMetadata account
+    UpdateProgramMetadata {},
+    /// Creates native SOL treasury account for a Governance account
+    CreateNativeTreasury,
+    /// Revokes (burns) membership governing tokens for the given TokenOwnerRecord
+    RevokeGoverningTokens {
+        /// The amount to revoke
+        amount: u64,
+    },
+    /// Refunds ProposalDeposit once the given proposal is no longer active
+    RefundProposalDeposit {},
+    /// Transitions an off-chain or manually executable Proposal from Succeeded into Completed state
+    CompleteProposal {},
+    /// Adds a required signatory to the Governance
+    AddRequiredSignatory {
+        /// Required signatory to add to the Governance
+        signatory: Pubkey,
+    },
+    /// Removes a required signatory from the Governance
+    RemoveRequiredSignatory,
+}
+
+/// Authority allowed to add a signatory to a proposal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddSignatoryAuthority {
+    /// The proposal owner adds the signatory
+    ProposalOwner {
+        /// Owner or delegate of the proposal owner record
+        governance_authority: Pubkey,
+        /// Token owner record of the proposal owner
+        token_owner_record: Pubkey,
+    },
+    /// Anyone adds a signatory required by the governance
+    None,
+}
+
+/// Pushes the realm config account followed by the optional voter weight addin records
+fn with_realm_config_accounts(
+    program_id: &Pubkey,
+    accounts: &mut Vec<AccountMeta>,
+    realm: &Pubkey,
+    voter_weight_record: Option<&Pubkey>,
+    max_voter_weight_record: Option<&Pubkey>,
+) {
+    accounts.push(AccountMeta::new_readonly(
+        get_realm_config_address(program_id, realm),
+        false,
+    ));
+    if let Some(voter_weight_record) = voter_weight_record {
+        accounts.push(AccountMeta::new_readonly(*voter_weight_record, false));
+    }
+    if let Some(max_voter_weight_record) = max_voter_weight_record {
+        accounts.push(AccountMeta::new_readonly(*max_voter_weight_record, false));
+    }
+}
+
+/// Creates CreateProposal instruction, the proposal address is derived from the seed
+#[allow(clippy::too_many_arguments)]
+pub fn create_proposal(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    proposal_owner_record: &Pubkey,
+    governance_authority: &Pubkey,
+    payer: &Pubkey,
+    voter_weight_record: Option<&Pubkey>,
+    realm: &Pubkey,
+    name: String,
+    description_link: String,
+    governing_token_mint: &Pubkey,
+    vote_type: VoteType,
+    options: Vec<String>,
+    use_deny_option: bool,
+    proposal_seed: &Pubkey,
+) -> Instruction {
+    let proposal =
+        get_proposal_address(program_id, governance, governing_token_mint, proposal_seed);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(proposal, false),
+        AccountMeta::new(*governance, false),
+        AccountMeta::new(*proposal_owner_record, false),
+        AccountMeta::new_readonly(*governing_token_mint, false),
+        AccountMeta::new_readonly(*governance_authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    with_realm_config_accounts(program_id, &mut accounts, realm, voter_weight_record, None);
+    accounts.push(AccountMeta::new(
+        get_proposal_deposit_address(program_id, &proposal, payer),
+        false,
+    ));
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::CreateProposal {
+            name,
+            description_link,
+            vote_type,
+            options,
+            use_deny_option,
+            proposal_seed: *proposal_seed,
+        },
+        accounts,
+    )
+}
+
+/// Creates AddSignatory instruction
+pub fn add_signatory(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    add_signatory_authority: &AddSignatoryAuthority,
+    payer: &Pubkey,
+    signatory: &Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new(
+            get_signatory_record_address(program_id, proposal, signatory),
+            false,
+        ),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    match add_signatory_authority {
+        AddSignatoryAuthority::ProposalOwner {
+            governance_authority,
+            token_owner_record,
+        } => {
+            accounts.push(AccountMeta::new_readonly(*token_owner_record, false));
+            accounts.push(AccountMeta::new_readonly(*governance_authority, true));
+        }
+        AddSignatoryAuthority::None => accounts.push(AccountMeta::new_readonly(
+            get_required_signatory_address(program_id, governance, signatory),
+            false,
+        )),
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::AddSignatory {
+            signatory: *signatory,
+        },
+        accounts,
+    )
+}
+
+/// Creates InsertTransaction instruction adding the instructions to the proposal
+/// option as one transaction
+#[allow(clippy::too_many_arguments)]
+pub fn insert_transaction(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    token_owner_record: &Pubkey,
+    governance_authority: &Pubkey,
+    payer: &Pubkey,
+    option_index: u8,
+    index: u16,
+    hold_up_time: u32,
+    instructions: Vec<InstructionData>,
+) -> Instruction {
+    let proposal_transaction =
+        get_proposal_transaction_address(program_id, proposal, option_index, index);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new_readonly(*token_owner_record, false),
+        AccountMeta::new_readonly(*governance_authority, true),
+        AccountMeta::new(proposal_transaction, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::InsertTransaction {
+            option_index,
+            index,
+            hold_up_time,
+            instructions,
+        },
+        accounts,
+    )
+}
+
+/// Creates SignOffProposal instruction, signed off by the proposal owner when
+/// its record is given and by a signatory otherwise
+pub fn sign_off_proposal(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    signatory: &Pubkey,
+    proposal_owner_record: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new_readonly(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new_readonly(*signatory, true),
+    ];
+    match proposal_owner_record {
+        Some(proposal_owner_record) => {
+            accounts.push(AccountMeta::new_readonly(*proposal_owner_record, false))
+        }
+        None => accounts.push(AccountMeta::new(
+            get_signatory_record_address(program_id, proposal, signatory),
+            false,
+        )),
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::SignOffProposal,
+        accounts,
+    )
+}
+
+/// Creates CastVote instruction
+#[allow(clippy::too_many_arguments)]
+pub fn cast_vote(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    proposal_owner_record: &Pubkey,
+    voter_token_owner_record: &Pubkey,
+    governance_authority: &Pubkey,
+    vote_governing_token_mint: &Pubkey,
+    payer: &Pubkey,
+    voter_weight_record: Option<&Pubkey>,
+    max_voter_weight_record: Option<&Pubkey>,
+    vote: Vote,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new(*proposal_owner_record, false),
+        AccountMeta::new(*voter_token_owner_record, false),
+        AccountMeta::new_readonly(*governance_authority, true),
+        AccountMeta::new(
+            get_vote_record_address(program_id, proposal, voter_token_owner_record),
+            false,
+        ),
+        AccountMeta::new_readonly(*vote_governing_token_mint, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    with_realm_config_accounts(
+        program_id,
+        &mut accounts,
+        realm,
+        voter_weight_record,
+        max_voter_weight_record,
+    );
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::CastVote { vote },
+        accounts,
+    )
+}
+
+/// Creates FinalizeVote instruction
+pub fn finalize_vote(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    proposal_owner_record: &Pubkey,
+    governing_token_mint: &Pubkey,
+    max_voter_weight_record: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*realm, false),
+        AccountMeta::new(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new(*proposal_owner_record, false),
+        AccountMeta::new_readonly(*governing_token_mint, false),
+    ];
+    with_realm_config_accounts(
+        program_id,
+        &mut accounts,
+        realm,
+        None,
+        max_voter_weight_record,
+    );
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::FinalizeVote {},
+        accounts,
+    )
+}
+
+/// Creates ExecuteTransaction instruction for the transaction holding the
+/// instructions. The governance and its native treasury sign through the
+/// governance program, the other signers have to sign the execution
+pub fn execute_transaction(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    proposal: &Pubkey,
+    option_index: u8,
+    index: u16,
+    instructions: &[InstructionData],
+) -> Instruction {
+    let native_treasury = get_native_treasury_address(program_id, governance);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*governance, false),
+        AccountMeta::new(*proposal, false),
+        AccountMeta::new(
+            get_proposal_transaction_address(program_id, proposal, option_index, index),
+            false,
+        ),
+    ];
+    for instruction in instructions {
+        accounts.push(AccountMeta::new_readonly(instruction.program_id, false));
+        accounts.extend(instruction.accounts.iter().map(|account| AccountMeta {
+            pubkey: account.pubkey,
+            is_signer: account.is_signer
+                && account.pubkey != *governance
+                && account.pubkey != native_treasury,
+            is_writable: account.is_writable,
+        }));
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::ExecuteTransaction,
+        accounts,
+    )
+}
+
+/// Creates SetGovernanceConfig instruction, it has to be executed by a proposal
+/// of the governance as the governance signs it
+pub fn set_governance_config(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    config: GovernanceConfig,
+) -> Instruction {
+    let accounts = vec![AccountMeta::new(*governance, true)];
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::SetGovernanceConfig { config },
+        accounts,
+    )
+}
+
+/// Creates SetRealmAuthority instruction, the new authority is omitted when
+/// the authority is removed
+pub fn set_realm_authority(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    new_realm_authority: Option<&Pubkey>,
+    action: SetRealmAuthorityAction,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+    ];
+    if action != SetRealmAuthorityAction::Remove {
+        if let Some(new_realm_authority) = new_realm_authority {
+            accounts.push(AccountMeta::new_readonly(*new_realm_authority, false));
+        }
+    }
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::SetRealmAuthority { action },
+        accounts,
+    )
+}
+
+/// Creates SetRealmConfig instruction, the addins are used when given. Without
+/// a council mint the council of the realm is removed for good
+#[allow(clippy::too_many_arguments)]
+pub fn set_realm_config(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    realm_authority: &Pubkey,
+    council_token_mint: Option<&Pubkey>,
+    payer: &Pubkey,
+    community_voter_weight_addin: Option<&Pubkey>,
+    max_community_voter_weight_addin: Option<&Pubkey>,
+    min_community_weight_to_create_governance: u64,
+    community_mint_max_voter_weight_source: MintMaxVoterWeightSource,
+    council_voter_weight_addin: Option<&Pubkey>,
+    max_council_voter_weight_addin: Option<&Pubkey>,
+    community_token_type: GoverningTokenType,
+    council_token_type: GoverningTokenType,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*realm, false),
+        AccountMeta::new_readonly(*realm_authority, true),
+    ];
+    if let Some(council_token_mint) = council_token_mint {
+        accounts.push(AccountMeta::new_readonly(*council_token_mint, false));
+        accounts.push(AccountMeta::new(
+            get_governing_token_holding_address(program_id, realm, council_token_mint),
+            false,
+        ));
+    }
+    accounts.push(AccountMeta::new_readonly(system_program::id(), false));
+    accounts.push(AccountMeta::new(
+        get_realm_config_address(program_id, realm),
+        false,
+    ));
+    for addin in [
+        community_voter_weight_addin,
+        max_community_voter_weight_addin,
+        council_voter_weight_addin,
+        max_council_voter_weight_addin,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        accounts.push(AccountMeta::new_readonly(*addin, false));
+    }
+    accounts.push(AccountMeta::new(*payer, true));
+
+    let config_args = RealmConfigArgs {
+        use_council_mint: council_token_mint.is_some(),
+        min_community_weight_to_create_governance,
+        community_mint_max_voter_weight_source,
+        community_token_config_args: GoverningTokenConfigArgs {
+            use_voter_weight_addin: community_voter_weight_addin.is_some(),
+            use_max_voter_weight_addin: max_community_voter_weight_addin.is_some(),
+            token_type: community_token_type,
+        },
+        council_token_config_args: GoverningTokenConfigArgs {
+            use_voter_weight_addin: council_voter_weight_addin.is_some(),
+            use_max_voter_weight_addin: max_council_voter_weight_addin.is_some(),
+            token_type: council_token_type,
+        },
+    };
+
+    Instruction::new_with_borsh(
+        *program_id,
+        &GovernanceInstruction::SetRealmConfig { config_args },
+        accounts,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::system_instruction;
+
+    use super::*;
+    use crate::{
+        governance::{
+            state::{VoteThreshold, VoteTipping},
+            DEFAULT_GOVERNANCE_PROGRAM_ID,
+        },
+        AccountMetaData,
+    };
+
+    const PROGRAM_ID: Pubkey = DEFAULT_GOVERNANCE_PROGRAM_ID;
+
+    #[test]
+    fn create_proposal_accounts() {
+        let [realm, governance, owner_record, authority, payer, voter_weight, mint, seed] =
+            [(); 8].map(|_| Pubkey::new_unique());
+        let proposal = get_proposal_address(&PROGRAM_ID, &governance, &mint, &seed);
+
+        let instruction = create_proposal(
+            &PROGRAM_ID,
+            &governance,
+            &owner_record,
+            &authority,
+            &payer,
+            Some(&voter_weight),
+            &realm,
+            "name".to_string(),
+            "link".to_string(),
+            &mint,
+            VoteType::SingleChoice,
+            vec!["yes".to_string()],
+            true,
+            &seed,
+        );
+
+        assert_eq!(instruction.data[0], 6);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(realm, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(governance, false),
+                AccountMeta::new(owner_record, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(get_realm_config_address(&PROGRAM_ID, &realm), false),
+                AccountMeta::new_readonly(voter_weight, false),
+                AccountMeta::new(
+                    get_proposal_deposit_address(&PROGRAM_ID, &proposal, &payer),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn add_signatory_accounts() {
+        let [governance, proposal, payer, signatory, authority, owner_record] =
+            [(); 6].map(|_| Pubkey::new_unique());
+        let common = vec![
+            AccountMeta::new_readonly(governance, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(
+                get_signatory_record_address(&PROGRAM_ID, &proposal, &signatory),
+                false,
+            ),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ];
+
+        let instruction = add_signatory(
+            &PROGRAM_ID,
+            &governance,
+            &proposal,
+            &AddSignatoryAuthority::ProposalOwner {
+                governance_authority: authority,
+                token_owner_record: owner_record,
+            },
+            &payer,
+            &signatory,
+        );
+        assert_eq!(instruction.data[0], 7);
+        assert_eq!(
+            instruction.accounts,
+            [
+                common.clone(),
+                vec![
+                    AccountMeta::new_readonly(owner_record, false),
+                    AccountMeta::new_readonly(authority, true),
+                ],
+            ]
+            .concat()
+        );
+
+        let instruction = add_signatory(
+            &PROGRAM_ID,
+            &governance,
+            &proposal,
+            &AddSignatoryAuthority::None,
+            &payer,
+            &signatory,
+        );
+        assert_eq!(instruction.data[0], 7);
+        assert_eq!(
+            instruction.accounts,
+            [
+                common,
+                vec![AccountMeta::new_readonly(
+                    get_required_signatory_address(&PROGRAM_ID, &governance, &signatory),
+                    false,
+                )],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn insert_transaction_accounts() {
+        let [governance, proposal, owner_record, authority, payer] =
+            [(); 5].map(|_| Pubkey::new_unique());
+
+        let instruction = insert_transaction(
+            &PROGRAM_ID,
+            &governance,
+            &proposal,
+            &owner_record,
+            &authority,
+            &payer,
+            0,
+            1,
+            0,
+            vec![],
+        );
+
+        assert_eq!(instruction.data[0], 9);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(governance, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new_readonly(owner_record, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(
+                    get_proposal_transaction_address(&PROGRAM_ID, &proposal, 0, 1),
+                    false
+                ),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn sign_off_proposal_accounts() {
+        let [realm, governance, proposal, signatory, owner_record] =
+            [(); 5].map(|_| Pubkey::new_unique());
+        let common = vec![
+            AccountMeta::new_readonly(realm, false),
+            AccountMeta::new_readonly(governance, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(signatory, true),
+        ];
+
+        let instruction = sign_off_proposal(
+            &PROGRAM_ID,
+            &realm,
+            &governance,
+            &proposal,
+            &signatory,
+            Some(&owner_record),
+        );
+        assert_eq!(instruction.data, [12]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                common.clone(),
+                vec![AccountMeta::new_readonly(owner_record, false)]
+            ]
+            .concat()
+        );
+
+        let instruction = sign_off_proposal(
+            &PROGRAM_ID,
+            &realm,
+            &governance,
+            &proposal,
+            &signatory,
+            None,
+        );
+        assert_eq!(instruction.data, [12]);
+        assert_eq!(
+            instruction.accounts,
+            [
+                common,
+                vec![AccountMeta::new(
+                    get_signatory_record_address(&PROGRAM_ID, &proposal, &signatory),
+                    false,
+                )],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn cast_vote_accounts() {
+        let [realm, governance, proposal, owner_record, voter_record, authority, mint, payer, voter_weight, max_voter_weight] =
+            [(); 10].map(|_| Pubkey::new_unique());
+
+        let instruction = cast_vote(
+            &PROGRAM_ID,
+            &realm,
+            &governance,
+            &proposal,
+            &owner_record,
+            &voter_record,
+            &authority,
+            &mint,
+            &payer,
+            Some(&voter_weight),
+            Some(&max_voter_weight),
+            Vote::Deny,
+        );
+
+        assert_eq!(instruction.data, [13, 1]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(realm, false),
+                AccountMeta::new(governance, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(owner_record, false),
+                AccountMeta::new(voter_record, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(
+                    get_vote_record_address(&PROGRAM_ID, &proposal, &voter_record),
+                    false
+                ),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+                AccountMeta::new_readonly(get_realm_config_address(&PROGRAM_ID, &realm), false),
+                AccountMeta::new_readonly(voter_weight, false),
+                AccountMeta::new_readonly(max_voter_weight, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn finalize_vote_accounts() {
+        let [realm, governance, proposal, owner_record, mint, max_voter_weight] =
+            [(); 6].map(|_| Pubkey::new_unique());
+
+        let instruction = finalize_vote(
+            &PROGRAM_ID,
+            &realm,
+            &governance,
+            &proposal,
+            &owner_record,
+            &mint,
+            Some(&max_voter_weight),
+        );
+
+        assert_eq!(instruction.data, [14]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(realm, false),
+                AccountMeta::new(governance, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(owner_record, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(get_realm_config_address(&PROGRAM_ID, &realm), false),
+                AccountMeta::new_readonly(max_voter_weight, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn execute_transaction_clears_governance_signers() {
+        let [governance, proposal, program, other_signer, readonly] =
+            [(); 5].map(|_| Pubkey::new_unique());
+        let native_treasury = get_native_treasury_address(&PROGRAM_ID, &governance);
+        let instructions = [InstructionData {
+            program_id: program,
+            accounts: vec![
+                AccountMetaData {
+                    pubkey: governance,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMetaData {
+                    pubkey: native_treasury,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMetaData {
+                    pubkey: other_signer,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                AccountMetaData {
+                    pubkey: readonly,
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+            data: vec![],
+        }];
+
+        let instruction =
+            execute_transaction(&PROGRAM_ID, &governance, &proposal, 1, 2, &instructions);
+
+        assert_eq!(instruction.data, [16]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new_readonly(governance, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new(
+                    get_proposal_transaction_address(&PROGRAM_ID, &proposal, 1, 2),
+                    false
+                ),
+                AccountMeta::new_readonly(program, false),
+                AccountMeta::new(governance, false),
+                AccountMeta::new(native_treasury, false),
+                AccountMeta::new_readonly(other_signer, true),
+                AccountMeta::new_readonly(readonly, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_governance_config_accounts() {
+        let governance = Pubkey::new_unique();
+        let config = GovernanceConfig {
+            community_vote_threshold: VoteThreshold::YesVotePercentage(60),
+            min_community_weight_to_create_proposal: 1,
+            min_transaction_hold_up_time: 0,
+            voting_base_time: 259_200,
+            community_vote_tipping: VoteTipping::Strict,
+            council_vote_threshold: VoteThreshold::Disabled,
+            council_veto_vote_threshold: VoteThreshold::Disabled,
+            min_council_weight_to_create_proposal: 1,
+            council_vote_tipping: VoteTipping::Disabled,
+            community_veto_vote_threshold: VoteThreshold::Disabled,
+            voting_cool_off_time: 0,
+            deposit_exempt_proposal_count: 10,
+        };
+
+        let instruction = set_governance_config(&PROGRAM_ID, &governance, config);
+
+        assert_eq!(instruction.data[0], 19);
+        assert_eq!(
+            instruction.accounts,
+            vec![AccountMeta::new(governance, true)]
+        );
+    }
+
+    #[test]
+    fn set_realm_authority_accounts() {
+        let [realm, authority, new_authority] = [(); 3].map(|_| Pubkey::new_unique());
+
+        let instruction = set_realm_authority(
+            &PROGRAM_ID,
+            &realm,
+            &authority,
+            Some(&new_authority),
+            SetRealmAuthorityAction::SetChecked,
+        );
+        assert_eq!(instruction.data, [21, 1]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(realm, false),
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new_readonly(new_authority, false),
+            ]
+        );
+
+        let instruction = set_realm_authority(
+            &PROGRAM_ID,
+            &realm,
+            &authority,
+            Some(&new_authority),
+            SetRealmAuthorityAction::Remove,
+        );
+        assert_eq!(instruction.data, [21, 2]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(realm, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_realm_config_discriminant() {
+        let [realm, authority, payer] = [(); 3].map(|_| Pubkey::new_unique());
+
+        let instruction = set_realm_config(
+            &PROGRAM_ID,
+            &realm,
+            &authority,
+            None,
+            &payer,
+            None,
+            None,
+            0,
+            MintMaxVoterWeightSource::SupplyFraction(10_000_000_000),
+            None,
+            None,
+            GoverningTokenType::Liquid,
+            GoverningTokenType::Liquid,
+        );
+
+        assert_eq!(instruction.data[0], 22);
+    }
+
+    #[test]
+    fn insert_transaction_data_round_trip() {
+        let instructions: Vec<InstructionData> = vec![
+            system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), 1).into(),
+            system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), 2).into(),
+        ];
+        let instruction = insert_transaction(
+            &DEFAULT_GOVERNANCE_PROGRAM_ID,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            1,
+            258,
+            3600,
+            instructions.clone(),
+        );
+
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&instruction.data).unwrap(),
+            GovernanceInstruction::InsertTransaction {
+                option_index: 1,
+                index: 258,
+                hold_up_time: 3600,
+                instructions,
+            }
+        );
+    }
+}
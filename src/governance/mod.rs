@@ -0,0 +1,73 @@
+//! Minimal mirror of the spl-governance program interface
+//!
+//! Only the parts needed to build and decode proposal instructions are
+//! replicated, the borsh layouts match spl-governance v3
+
+pub mod instruction;
+pub mod pda;
+pub mod state;
+
+use std::str::FromStr;
+
+use solana_program::{pubkey, pubkey::Pubkey};
+
+use crate::governance::pda::{get_governance_address, get_native_treasury_address};
+
+/// Address of the spl-governance program instance deployed by Solana Labs
+pub const DEFAULT_GOVERNANCE_PROGRAM_ID: Pubkey =
+    pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+/// Account of a governance able to act as the authority of governed accounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovernanceAuthority {
+    /// The governance account itself
+    Governance,
+    /// The native SOL treasury of the governance
+    NativeTreasury,
+}
+
+impl FromStr for GovernanceAuthority {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "governance" => Ok(GovernanceAuthority::Governance),
+            "native-treasury" => Ok(GovernanceAuthority::NativeTreasury),
+            _ => Err(format!(
+                "unknown governance authority `{}`, expected governance or native-treasury",
+                value
+            )),
+        }
+    }
+}
+
+/// Returns the address of the governance account acting as the authority
+pub fn get_governance_authority_address(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    authority: GovernanceAuthority,
+) -> Pubkey {
+    match authority {
+        GovernanceAuthority::Governance => *governance,
+        GovernanceAuthority::NativeTreasury => get_native_treasury_address(program_id, governance),
+    }
+}
+
+/// Returns which account of the governance over the governed account the
+/// authority is, `None` if it is neither of them
+pub fn find_governance_authority(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governed_account: &Pubkey,
+    authority: &Pubkey,
+) -> Option<GovernanceAuthority> {
+    let governance = get_governance_address(program_id, realm, governed_account);
+
+    if *authority == governance {
+        Some(GovernanceAuthority::Governance)
+    } else if *authority == get_native_treasury_address(program_id, &governance) {
+        Some(GovernanceAuthority::NativeTreasury)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,272 @@
+//! Program derived addresses of the spl-governance accounts, seeds match spl-governance v3
+
+use solana_program::pubkey::Pubkey;
+
+/// Seed prefix shared by most of the governance PDAs
+pub const PROGRAM_AUTHORITY_SEED: &[u8] = b"governance";
+
+/// Returns the Realm address for the realm name
+pub fn get_realm_address(program_id: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(&[PROGRAM_AUTHORITY_SEED, name.as_bytes()], program_id).0
+}
+
+/// Returns the Governance address of the governed account (governance seed) in the realm
+pub fn get_governance_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governance_seed: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"account-governance",
+            realm.as_ref(),
+            governance_seed.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the native SOL treasury address of the governance
+pub fn get_native_treasury_address(program_id: &Pubkey, governance: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"native-treasury", governance.as_ref()], program_id).0
+}
+
+/// Returns the TokenOwnerRecord address of the governing token owner in the realm
+pub fn get_token_owner_record_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+    governing_token_owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            realm.as_ref(),
+            governing_token_mint.as_ref(),
+            governing_token_owner.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the Proposal address of the proposal seed in the governance
+pub fn get_proposal_address(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    governing_token_mint: &Pubkey,
+    proposal_seed: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            governance.as_ref(),
+            governing_token_mint.as_ref(),
+            proposal_seed.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the ProposalTransaction address of the transaction at the index of the proposal option
+pub fn get_proposal_transaction_address(
+    program_id: &Pubkey,
+    proposal: &Pubkey,
+    option_index: u8,
+    index: u16,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            proposal.as_ref(),
+            &option_index.to_le_bytes(),
+            &index.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the SignatoryRecord address of the signatory of the proposal
+pub fn get_signatory_record_address(
+    program_id: &Pubkey,
+    proposal: &Pubkey,
+    signatory: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            proposal.as_ref(),
+            signatory.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the VoteRecord address of the token owner record voting on the proposal
+pub fn get_vote_record_address(
+    program_id: &Pubkey,
+    proposal: &Pubkey,
+    token_owner_record: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            proposal.as_ref(),
+            token_owner_record.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the RealmConfig address of the realm
+pub fn get_realm_config_address(program_id: &Pubkey, realm: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"realm-config", realm.as_ref()], program_id).0
+}
+
+/// Returns the ProposalDeposit address of the payer of the proposal deposit
+pub fn get_proposal_deposit_address(
+    program_id: &Pubkey,
+    proposal: &Pubkey,
+    proposal_deposit_payer: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"proposal-deposit",
+            proposal.as_ref(),
+            proposal_deposit_payer.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the RequiredSignatory address of the signatory of the governance
+pub fn get_required_signatory_address(
+    program_id: &Pubkey,
+    governance: &Pubkey,
+    signatory: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"required-signatory",
+            governance.as_ref(),
+            signatory.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+/// Returns the governing token holding account of the mint in the realm
+pub fn get_governing_token_holding_address(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            PROGRAM_AUTHORITY_SEED,
+            realm.as_ref(),
+            governing_token_mint.as_ref(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn pubkey(value: &str) -> Pubkey {
+        Pubkey::from_str(value).unwrap()
+    }
+
+    /// spl-governance instance of the Mango DAO on mainnet
+    const MANGO_GOVERNANCE_PROGRAM: &str = "GqTPL6qRf5aUuqscLh8Rg2HTxPUXfhhAXDptTLhp1t2J";
+    /// Mango DAO realm on mainnet
+    const MANGO_REALM: &str = "DPiH3H3c7t47BMxqTxLsuPQpEC6Kne8GA9VXbxpnZxFE";
+    /// MNGO governing token mint
+    const MNGO_MINT: &str = "MangoCzJ36AjZyKwVj3VnYU4GTonjfVEnJmvvWaxLac";
+
+    #[test]
+    fn realm_address() {
+        assert_eq!(
+            get_realm_address(&pubkey(MANGO_GOVERNANCE_PROGRAM), "Mango"),
+            pubkey(MANGO_REALM)
+        );
+    }
+
+    // the addresses below pin the seeds of the Mango DAO accounts against
+    // regressions, they are not taken from the chain
+
+    #[test]
+    fn governance_and_native_treasury_addresses() {
+        let program_id = pubkey(MANGO_GOVERNANCE_PROGRAM);
+        let governance =
+            get_governance_address(&program_id, &pubkey(MANGO_REALM), &pubkey(MNGO_MINT));
+
+        assert_eq!(
+            governance,
+            pubkey("4GLa6tssQ5JGua6KRP6VtWXMqa32MgTjoKZEkaLYwtWa")
+        );
+        assert_eq!(
+            get_native_treasury_address(&program_id, &governance),
+            pubkey("BepfMjaxLX9UJXAEHjVzb4crJKLYYCW1Z2vkWzARM3fi")
+        );
+    }
+
+    #[test]
+    fn token_owner_record_address() {
+        assert_eq!(
+            get_token_owner_record_address(
+                &pubkey(MANGO_GOVERNANCE_PROGRAM),
+                &pubkey(MANGO_REALM),
+                &pubkey(MNGO_MINT),
+                &pubkey("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"),
+            ),
+            pubkey("73huYsqQZ56Wf2p9hH9PLDcKUQSWMu1XZnDxT6dgCLG7")
+        );
+    }
+
+    #[test]
+    fn proposal_address() {
+        assert_eq!(
+            get_proposal_address(
+                &pubkey(MANGO_GOVERNANCE_PROGRAM),
+                &pubkey("4GLa6tssQ5JGua6KRP6VtWXMqa32MgTjoKZEkaLYwtWa"),
+                &pubkey(MNGO_MINT),
+                &pubkey("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"),
+            ),
+            pubkey("dgvRxvRrJ4rrSYd3SzrcP6RB1Rs7YF466wpAq2frecs")
+        );
+    }
+
+    #[test]
+    fn proposal_transaction_seeds() {
+        let program_id = pubkey(MANGO_GOVERNANCE_PROGRAM);
+        let proposal = pubkey("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
+
+        // the option index is a single byte and the index two little-endian bytes
+        let expected = Pubkey::find_program_address(
+            &[PROGRAM_AUTHORITY_SEED, proposal.as_ref(), &[1], &[2, 1]],
+            &program_id,
+        )
+        .0;
+        assert_eq!(
+            get_proposal_transaction_address(&program_id, &proposal, 1, 258),
+            expected
+        );
+        assert_ne!(
+            get_proposal_transaction_address(&program_id, &proposal, 1, 513),
+            expected
+        );
+    }
+}
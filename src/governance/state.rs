@@ -0,0 +1,294 @@
+use std::str::FromStr;
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+
+/// The type of the vote threshold used to resolve a vote on a Proposal
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum VoteThreshold {
+    /// Voting threshold of Yes votes in % of the governing token supply
+    YesVotePercentage(u8),
+    /// Voting threshold of all votes in % of the governing token supply (not implemented)
+    QuorumPercentage(u8),
+    /// Disabled vote threshold indicates the given voting population is not allowed to vote
+    Disabled,
+}
+
+impl FromStr for VoteThreshold {
+    type Err = String;
+
+    /// Parses `disabled` or the percentage of Yes votes
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "disabled" {
+            return Ok(VoteThreshold::Disabled);
+        }
+        match value.trim_end_matches('%').parse() {
+            Ok(percentage @ 1..=100) => Ok(VoteThreshold::YesVotePercentage(percentage)),
+            _ => Err(format!(
+                "invalid vote threshold `{}`, expected a percentage from 1 to 100 or disabled",
+                value
+            )),
+        }
+    }
+}
+
+/// The type of vote tipping used to decide whether a vote can end before the voting time
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum VoteTipping {
+    /// Tip when there is no way for another option to win and the vote threshold is reached
+    Strict,
+    /// Tip when an option reaches the vote threshold and has more votes than any other option
+    Early,
+    /// Never tip the vote early
+    Disabled,
+}
+
+impl FromStr for VoteTipping {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "strict" => Ok(VoteTipping::Strict),
+            "early" => Ok(VoteTipping::Early),
+            "disabled" => Ok(VoteTipping::Disabled),
+            _ => Err(format!(
+                "unknown vote tipping `{}`, expected strict, early or disabled",
+                value
+            )),
+        }
+    }
+}
+
+/// Governance config
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct GovernanceConfig {
+    /// The type of the vote threshold used for community vote
+    pub community_vote_threshold: VoteThreshold,
+    /// Minimum community weight a governance token owner must possess to create a proposal
+    pub min_community_weight_to_create_proposal: u64,
+    /// Minimum waiting time in seconds for a transaction to be executed after the vote ends
+    pub min_transaction_hold_up_time: u32,
+    /// Time limit in seconds for a proposal to be open for voting
+    pub voting_base_time: u32,
+    /// Conditions under which a community vote will complete early
+    pub community_vote_tipping: VoteTipping,
+    /// The type of the vote threshold used for council vote
+    pub council_vote_threshold: VoteThreshold,
+    /// The threshold for council veto votes
+    pub council_veto_vote_threshold: VoteThreshold,
+    /// Minimum council weight a governance token owner must possess to create a proposal
+    pub min_council_weight_to_create_proposal: u64,
+    /// Conditions under which a council vote will complete early
+    pub council_vote_tipping: VoteTipping,
+    /// The threshold for community veto votes
+    pub community_veto_vote_threshold: VoteThreshold,
+    /// Voting cool-off time in seconds during which only relinquish and veto votes are allowed
+    pub voting_cool_off_time: u32,
+    /// Number of proposals exempt from the proposal deposit
+    pub deposit_exempt_proposal_count: u8,
+}
+
+/// The source of the max vote weight used for voting
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum MintMaxVoterWeightSource {
+    /// Fraction (10^10 precision) of the governing mint supply is used as the max vote weight
+    SupplyFraction(u64),
+    /// Absolute value, irrelevant of the actual mint supply, is used as the max vote weight
+    Absolute(u64),
+}
+
+impl FromStr for MintMaxVoterWeightSource {
+    type Err = String;
+
+    /// Parses `supply-fraction:<fraction>`, with 10^10 being the full supply,
+    /// or `absolute:<weight>`
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let source = match value.split_once(':') {
+            Some(("supply-fraction", fraction)) => fraction
+                .parse()
+                .ok()
+                .map(MintMaxVoterWeightSource::SupplyFraction),
+            Some(("absolute", weight)) => {
+                weight.parse().ok().map(MintMaxVoterWeightSource::Absolute)
+            }
+            _ => None,
+        };
+        source.ok_or_else(|| {
+            format!(
+                "invalid max voter weight source `{}`, expected supply-fraction:<fraction> or absolute:<weight>",
+                value
+            )
+        })
+    }
+}
+
+/// The type of the governing token defining the operations allowed on it
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum GoverningTokenType {
+    /// Liquid token can be deposited and withdrawn at any time
+    Liquid,
+    /// Membership token can be revoked by the realm and can't be withdrawn by its owner
+    Membership,
+    /// Dormant token can't be deposited at all, only withdrawn and revoked
+    Dormant,
+}
+
+impl FromStr for GoverningTokenType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "liquid" => Ok(GoverningTokenType::Liquid),
+            "membership" => Ok(GoverningTokenType::Membership),
+            "dormant" => Ok(GoverningTokenType::Dormant),
+            _ => Err(format!(
+                "unknown governing token type `{}`, expected liquid, membership or dormant",
+                value
+            )),
+        }
+    }
+}
+
+/// Realm config of a governing token passed as instruction arguments
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct GoverningTokenConfigArgs {
+    /// Indicates whether an external addin program should be used to provide voter weights
+    pub use_voter_weight_addin: bool,
+    /// Indicates whether an external addin program should be used to provide max voter weight
+    pub use_max_voter_weight_addin: bool,
+    /// Governing token type
+    pub token_type: GoverningTokenType,
+}
+
+/// Realm config passed as instruction arguments
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct RealmConfigArgs {
+    /// Indicates whether council_mint should be used
+    pub use_council_mint: bool,
+    /// Minimum number of community tokens a governance token owner must possess to create a governance
+    pub min_community_weight_to_create_governance: u64,
+    /// The source used for community mint max vote weight source
+    pub community_mint_max_voter_weight_source: MintMaxVoterWeightSource,
+    /// Community token config args
+    pub community_token_config_args: GoverningTokenConfigArgs,
+    /// Council token config args
+    pub council_token_config_args: GoverningTokenConfigArgs,
+}
+
+/// Set Realm Authority action
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum SetRealmAuthorityAction {
+    /// Sets realm authority without any checks
+    SetUnchecked,
+    /// Sets realm authority and checks the new authority is one of the realm's governances
+    SetChecked,
+    /// Removes realm authority
+    Remove,
+}
+
+/// Maximum number of options of a proposal accepted by spl-governance v3
+pub const MAX_PROPOSAL_OPTIONS: u8 = 10;
+
+/// Type of MultiChoice
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum MultiChoiceType {
+    /// Each voter can split their full weight between the options
+    FullWeight,
+    /// Each voter assigns their weight to options in arbitrary proportion (not implemented)
+    Weighted,
+}
+
+/// Proposal option vote type
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum VoteType {
+    /// Single choice vote with mutually exclusive choices
+    SingleChoice,
+    /// Multiple options can be selected with up to max_voter_options per voter
+    MultiChoice {
+        /// Type of MultiChoice
+        choice_type: MultiChoiceType,
+        /// The min number of options a voter must choose
+        min_voter_options: u8,
+        /// The max number of options a voter can choose
+        max_voter_options: u8,
+        /// The max number of winning options
+        max_winning_options: u8,
+    },
+}
+
+/// Voter choice for a proposal option
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct VoteChoice {
+    /// The rank given to the choice by voter (not used yet)
+    pub rank: u8,
+    /// The voter's weight percentage given by the voter to the choice
+    pub weight_percentage: u8,
+}
+
+/// User's vote
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub enum Vote {
+    /// Vote approving choices
+    Approve(Vec<VoteChoice>),
+    /// Vote rejecting proposal
+    Deny,
+    /// Declare indifference to proposal
+    Abstain,
+    /// Veto proposal
+    Veto,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn governance_config_layout() {
+        let config = GovernanceConfig {
+            community_vote_threshold: VoteThreshold::YesVotePercentage(60),
+            min_community_weight_to_create_proposal: 1,
+            min_transaction_hold_up_time: 2,
+            voting_base_time: 3,
+            community_vote_tipping: VoteTipping::Early,
+            council_vote_threshold: VoteThreshold::QuorumPercentage(50),
+            council_veto_vote_threshold: VoteThreshold::Disabled,
+            min_council_weight_to_create_proposal: 4,
+            council_vote_tipping: VoteTipping::Disabled,
+            community_veto_vote_threshold: VoteThreshold::YesVotePercentage(70),
+            voting_cool_off_time: 5,
+            deposit_exempt_proposal_count: 6,
+        };
+
+        let expected = [
+            &[0, 60][..],
+            &1u64.to_le_bytes(),
+            &2u32.to_le_bytes(),
+            &3u32.to_le_bytes(),
+            &[1],
+            &[1, 50],
+            &[2],
+            &4u64.to_le_bytes(),
+            &[2],
+            &[0, 70],
+            &5u32.to_le_bytes(),
+            &[6],
+        ]
+        .concat();
+        assert_eq!(borsh::to_vec(&config).unwrap(), expected);
+        assert_eq!(GovernanceConfig::try_from_slice(&expected).unwrap(), config);
+    }
+
+    #[test]
+    fn parse_vote_threshold() {
+        assert_eq!("disabled".parse(), Ok(VoteThreshold::Disabled));
+        assert_eq!("60".parse(), Ok(VoteThreshold::YesVotePercentage(60)));
+        assert_eq!("60%".parse(), Ok(VoteThreshold::YesVotePercentage(60)));
+        assert_eq!("100".parse(), Ok(VoteThreshold::YesVotePercentage(100)));
+    }
+
+    #[test]
+    fn reject_vote_threshold_out_of_range() {
+        for value in ["0", "101", "0%", "-1", "sixty"] {
+            assert!(value.parse::<VoteThreshold>().is_err(), "{}", value);
+        }
+    }
+}
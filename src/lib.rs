@@ -0,0 +1,192 @@
+//! Builds and decodes the base64 encoded `InstructionData` accepted by
+//! spl-governance proposals
+
+pub mod address_book;
+pub mod amount;
+pub mod bundle;
+pub mod decoder;
+pub mod error;
+pub mod governance;
+pub mod program_data;
+pub mod token;
+pub mod treasury;
+
+use std::{fmt, str::FromStr};
+
+use base64::{
+    alphabet,
+    engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::error::Error;
+
+/// InstructionData wrapper. It can be removed once Borsh serialization for
+/// Instruction is supported in the SDK
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct InstructionData {
+    /// Pubkey of the instruction processor that executes this instruction
+    pub program_id: Pubkey,
+    /// Metadata for what accounts should be passed to the instruction processor
+    pub accounts: Vec<AccountMetaData>,
+    /// Opaque data passed to the instruction processor
+    pub data: Vec<u8>,
+}
+
+/// Account metadata used to define Instructions
+#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct AccountMetaData {
+    /// An account's public key
+    pub pubkey: Pubkey,
+    /// True if an Instruction requires a Transaction signature matching
+    /// `pubkey`.
+    pub is_signer: bool,
+    /// True if the `pubkey` can be loaded as a read-write account.
+    pub is_writable: bool,
+}
+
+impl From<Instruction> for InstructionData {
+    fn from(instruction: Instruction) -> Self {
+        InstructionData {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|a| AccountMetaData {
+                    pubkey: a.pubkey,
+                    is_signer: a.is_signer,
+                    is_writable: a.is_writable,
+                })
+                .collect(),
+            data: instruction.data,
+        }
+    }
+}
+
+impl From<&InstructionData> for Instruction {
+    fn from(instruction: &InstructionData) -> Self {
+        Instruction {
+            program_id: instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|a| AccountMeta {
+                    pubkey: a.pubkey,
+                    is_signer: a.is_signer,
+                    is_writable: a.is_writable,
+                })
+                .collect(),
+            data: instruction.data.clone(),
+        }
+    }
+}
+
+impl fmt::Display for InstructionData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Program: {}", self.program_id)?;
+        writeln!(f, "Accounts:")?;
+        for (index, account) in self.accounts.iter().enumerate() {
+            writeln!(
+                f,
+                "  {:>2}: {} signer: {}, writable: {}",
+                index, account.pubkey, account.is_signer, account.is_writable
+            )?;
+        }
+        write!(f, "Data ({} bytes): ", self.data.len())?;
+        for byte in &self.data {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Engine decoding base64 produced with or without padding
+const DECODE_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Parses a base58 encoded pubkey
+pub fn parse_pubkey(value: &str) -> Result<Pubkey, Error> {
+    Pubkey::from_str(value).map_err(|_| Error::InvalidPubkey(value.to_string()))
+}
+
+/// Serializes the instruction as `InstructionData` and encodes it with base64
+pub fn encode(instruction: &Instruction) -> Result<String, Error> {
+    let instruction_data: InstructionData = instruction.clone().into();
+    let instruction_bytes = borsh::to_vec(&instruction_data).map_err(Error::Borsh)?;
+
+    // base64 encoded message is accepted as the input in the UI
+    Ok(general_purpose::STANDARD_NO_PAD.encode(&instruction_bytes))
+}
+
+/// Decodes base64 encoded `InstructionData`, as inserted into a proposal
+pub fn decode(encoded: &str) -> Result<InstructionData, Error> {
+    let instruction_bytes = DECODE_ENGINE.decode(encoded.trim())?;
+
+    deserialize_exact(&instruction_bytes)
+}
+
+/// Deserializes borsh data, failing if any bytes are left over
+pub fn deserialize_exact<T: BorshDeserialize>(bytes: &[u8]) -> Result<T, Error> {
+    let mut remaining = bytes;
+    let value = T::deserialize(&mut remaining).map_err(Error::Borsh)?;
+
+    match remaining.len() {
+        0 => Ok(value),
+        trailing => Err(Error::TrailingBytes(trailing)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction() -> Instruction {
+        Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[1, 2, 3, 4],
+            vec![
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+        )
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let instruction = instruction();
+
+        let decoded = decode(&encode(&instruction).unwrap()).unwrap();
+
+        assert_eq!(Instruction::from(&decoded), instruction);
+    }
+
+    #[test]
+    fn decode_padded_and_unpadded() {
+        let instruction = instruction();
+        let bytes = borsh::to_vec(&InstructionData::from(instruction.clone())).unwrap();
+        let padded = general_purpose::STANDARD.encode(&bytes);
+        let unpadded = general_purpose::STANDARD_NO_PAD.encode(&bytes);
+        assert_ne!(padded, unpadded);
+
+        assert_eq!(Instruction::from(&decode(&padded).unwrap()), instruction);
+        assert_eq!(Instruction::from(&decode(&unpadded).unwrap()), instruction);
+    }
+
+    #[test]
+    fn decode_trailing_bytes() {
+        let mut bytes = borsh::to_vec(&InstructionData::from(instruction())).unwrap();
+        bytes.extend([0, 0, 0]);
+
+        assert!(matches!(
+            decode(&general_purpose::STANDARD.encode(&bytes)),
+            Err(Error::TrailingBytes(3))
+        ));
+    }
+}
@@ -1,107 +1,148 @@
-use std::str::FromStr;
-
-use base64::{engine::general_purpose, Engine};
-use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use solana_program::{
-    bpf_loader_upgradeable::set_upgrade_authority,
-    instruction::{AccountMeta, Instruction},
-    pubkey::Pubkey,
+mod cli;
+mod manifest;
+
+use std::{error::Error as _, process, slice};
+
+use clap::Parser;
+use governance_upgrade_ix_base64_generator::{
+    bundle::Bundle, decode, decoder::DecoderRegistry, error::Error,
 };
+use solana_program::instruction::Instruction;
 
-/// InstructionData wrapper. It can be removed once Borsh serialization for
-/// Instruction is supported in the SDK
-#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
-pub struct InstructionData {
-    /// Pubkey of the instruction processor that executes this instruction
-    pub program_id: Pubkey,
-    /// Metadata for what accounts should be passed to the instruction processor
-    pub accounts: Vec<AccountMetaData>,
-    /// Opaque data passed to the instruction processor
-    pub data: Vec<u8>,
-}
+use crate::{
+    cli::{address_book, load_address_book, parse_bundle_steps, Cli, Command, InstructionCommand},
+    manifest::Manifest,
+};
 
-/// Account metadata used to define Instructions
-#[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize, BorshSchema)]
-pub struct AccountMetaData {
-    /// An account's public key
-    pub pubkey: Pubkey,
-    /// True if an Instruction requires a Transaction signature matching
-    /// `pubkey`.
-    pub is_signer: bool,
-    /// True if the `pubkey` can be loaded as a read-write account.
-    pub is_writable: bool,
-}
+fn main() {
+    if let Err(err) = load_address_book() {
+        exit_with_error(err);
+    }
+    let cli = Cli::try_parse().unwrap_or_else(|err| exit_with_usage_error(err));
 
-impl From<Instruction> for InstructionData {
-    fn from(instruction: Instruction) -> Self {
-        InstructionData {
-            program_id: instruction.program_id,
-            accounts: instruction
-                .accounts
-                .iter()
-                .map(|a| AccountMetaData {
-                    pubkey: a.pubkey,
-                    is_signer: a.is_signer,
-                    is_writable: a.is_writable,
-                })
-                .collect(),
-            data: instruction.data,
-        }
+    if let Err(err) = run(cli.command) {
+        exit_with_error(err);
     }
 }
 
-impl From<&InstructionData> for Instruction {
-    fn from(instruction: &InstructionData) -> Self {
-        Instruction {
-            program_id: instruction.program_id,
-            accounts: instruction
-                .accounts
-                .iter()
-                .map(|a| AccountMeta {
-                    pubkey: a.pubkey,
-                    is_signer: a.is_signer,
-                    is_writable: a.is_writable,
-                })
-                .collect(),
-            data: instruction.data.clone(),
+fn run(command: Command) -> Result<(), Error> {
+    match command {
+        Command::Decode {
+            encoded,
+            governance_program,
+            strict,
+        } => {
+            let mut registry = DecoderRegistry::default();
+            if let Some(governance_program) = governance_program {
+                registry.register_governance(governance_program);
+            }
+
+            let instruction = decode(&encoded)?;
+            if strict {
+                registry.decode(&instruction)?;
+            }
+
+            println!(
+                "{}",
+                address_book().annotate(&registry.describe(&instruction))
+            );
+        }
+        Command::Bundle { steps } => {
+            let commands =
+                parse_bundle_steps(&steps).unwrap_or_else(|err| exit_with_usage_error(err));
+
+            let mut bundle = Bundle::new();
+            for command in &commands {
+                bundle.extend(build(command)?);
+            }
+            print_bundle(&bundle, &registry(&commands))?;
+        }
+        Command::Manifest { path } => {
+            let manifest = Manifest::read(&path)?;
+            let commands = manifest.commands()?;
+
+            let mut bundle = Bundle::new();
+            for command in &commands {
+                bundle.extend(build(command)?);
+            }
+            if let Some(name) = &manifest.name {
+                println!("Proposal: {}", name);
+            }
+            print_bundle(&bundle, &registry(&commands))?;
         }
+        Command::Derive(args) => {
+            for (name, address) in args.addresses() {
+                println!(
+                    "{}: {}",
+                    name,
+                    address_book().annotate(&address.to_string())
+                );
+            }
+        }
+        Command::Instruction(command) => print_bundle(
+            &build(&command)?.into(),
+            &registry(slice::from_ref(&command)),
+        )?,
     }
-}
 
-fn main() {
-    // Arrange
-    // let program_address = Pubkey::from_str("78sycjkMouQ2HJnpnvDUzgBCt81jMJVZMf5rLhZ5bgrh").unwrap();
-    // let buffer_address = Pubkey::from_str("CjoWQim52bBVk9xZQJBoxwoiEcAHx68WTP8GrFKJdUKQ").unwrap();
-    // in the current context, governance is the same as the upgrade authority of governance program
-    // let governance = Pubkey::from_str("8Nm2CFjLx1Vnd1D1NvnCfdq3BJBzZ8aNRcCuTnhr7FVh").unwrap();
+    Ok(())
+}
 
-    let program_address = Pubkey::from_str("D9KEi2SGUuX71zgGYPBScScZagrm7J8jSEduBTF84xtj").unwrap();
-    let authority = Pubkey::from_str("C6DmyYh1KXNMAvdMzP845aP2WhXkfmvu6qaC9kQReKLQ").unwrap();
-    let new_authority = Pubkey::from_str("Bc1WrTZZUQyRQkKQNqcBqLpoxMQehx4mBXk3aVsJRxhp").unwrap();
+/// Builds the instructions of the command, reporting its warnings and, once
+/// the instructions are built, its notes
+fn build(command: &InstructionCommand) -> Result<Vec<Instruction>, Error> {
+    for warning in command.warnings() {
+        eprintln!("Warning: {}", warning);
+    }
 
-    let transfer_instruction =
-        set_upgrade_authority(&program_address, &authority, Some(&new_authority));
+    let instructions = command.instructions()?;
+    for note in command.notes() {
+        eprintln!("Note: {}", note);
+    }
+    Ok(instructions)
+}
 
-    // let upgrade_instruction = bpf_loader_upgradeable::upgrade(
-    //     &program_address,
-    //     &buffer_address,
-    //     &governance,
-    //     &governance,
-    // );
+/// Returns the decoder registry knowing the governance program instances of the commands
+fn registry(commands: &[InstructionCommand]) -> DecoderRegistry {
+    let mut registry = DecoderRegistry::default();
+    for governance_program in commands.iter().filter_map(|c| c.governance_program()) {
+        registry.register_governance(*governance_program);
+    }
+    registry
+}
 
-    // Act
-    let instruction_data: InstructionData = transfer_instruction.clone().into();
-    let mut instruction_bytes = vec![];
-    instruction_data.serialize(&mut instruction_bytes).unwrap();
+/// Prints the encoded instructions, numbered and summarised when there are several
+fn print_bundle(bundle: &Bundle, registry: &DecoderRegistry) -> Result<(), Error> {
+    let encoded = bundle.encode()?;
+    if let [encoded] = &encoded[..] {
+        println!("Encoded ix: {}", encoded);
+        return Ok(());
+    }
 
-    // base64 encoded message is accepted as the input in the UI
-    let encoded = general_purpose::STANDARD_NO_PAD.encode(&instruction_bytes);
+    for (index, encoded) in encoded.iter().enumerate() {
+        println!("Encoded ix #{}: {}", index + 1, encoded);
+    }
+    println!();
+    println!("{}", bundle.summary(registry));
 
-    // Assert
-    let instruction =
-        Instruction::from(&InstructionData::deserialize(&mut &instruction_bytes[..]).unwrap());
+    Ok(())
+}
 
-    assert_eq!(transfer_instruction, instruction);
+/// Exits with the exit code of the error category
+fn exit_with_error(err: Error) -> ! {
+    eprintln!("Error: {}", err);
+    process::exit(err.exit_code());
+}
 
-    println!("Encoded ix: {}", encoded);
+/// Exits on a command line error, arguments rejected by the generator's own
+/// parsers keep the exit code of their error category
+fn exit_with_usage_error(err: clap::Error) -> ! {
+    if let Some(source) = err
+        .source()
+        .and_then(|source| source.downcast_ref::<Error>())
+    {
+        let _ = err.print();
+        process::exit(source.exit_code());
+    }
+    err.exit()
 }
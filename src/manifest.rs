@@ -0,0 +1,237 @@
+use std::{error::Error as _, fs, path::Path};
+
+use governance_upgrade_ix_base64_generator::error::Error;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::cli::{parse_step, InstructionCommand};
+
+/// Proposal described in a TOML or JSON manifest file, e.g.
+///
+/// ```toml
+/// name = "Upgrade the program"
+///
+/// [[instructions]]
+/// kind = "upgrade"
+/// program = "D9KEi2SGUuX71zgGYPBScScZagrm7J8jSEduBTF84xtj"
+/// buffer = "CjoWQim52bBVk9xZQJBoxwoiEcAHx68WTP8GrFKJdUKQ"
+/// authority = "C6DmyYh1KXNMAvdMzP845aP2WhXkfmvu6qaC9kQReKLQ"
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    /// Title of the proposal
+    pub name: Option<String>,
+    /// Instructions of the proposal in execution order
+    pub instructions: Vec<ManifestInstruction>,
+}
+
+/// Instruction of a manifest, its kind and arguments match the CLI subcommands
+#[derive(Debug, Deserialize)]
+pub struct ManifestInstruction {
+    /// Name of the instruction subcommand, e.g. `set-upgrade-authority`
+    pub kind: String,
+    /// Arguments of the subcommand, keys may use either dashes or underscores,
+    /// arrays repeat the argument for each value
+    #[serde(flatten)]
+    pub args: Map<String, Value>,
+}
+
+impl Manifest {
+    /// Reads the manifest, files with the `.json` extension are parsed as JSON
+    /// and anything else as TOML
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            serde_json::from_str(&content).map_err(|err| Error::InvalidManifest(err.to_string()))
+        } else {
+            toml::from_str(&content).map_err(|err| Error::InvalidManifest(err.to_string()))
+        }
+    }
+
+    /// Parses the manifest instructions as CLI subcommands
+    pub fn commands(&self) -> Result<Vec<InstructionCommand>, Error> {
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(index, instruction)| {
+                parse_step(&instruction.to_args()?).map_err(|err| instruction_error(index, err))
+            })
+            .collect()
+    }
+}
+
+impl ManifestInstruction {
+    /// Converts the instruction into the command line arguments of its subcommand
+    fn to_args(&self) -> Result<Vec<String>, Error> {
+        let mut args = vec![self.kind.clone()];
+        for (name, value) in &self.args {
+            let flag = format!("--{}", name.replace('_', "-"));
+            match value {
+                Value::Bool(true) => args.push(flag),
+                Value::Bool(false) | Value::Null => {}
+                Value::Array(values) => {
+                    for value in values {
+                        args.extend([flag.clone(), scalar_arg(name, &self.kind, value)?]);
+                    }
+                }
+                value => args.extend([flag, scalar_arg(name, &self.kind, value)?]),
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Converts a string or number argument value of the instruction kind
+fn scalar_arg(name: &str, kind: &str, value: &Value) -> Result<String, Error> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        Value::Number(value) => Ok(value.to_string()),
+        _ => Err(Error::InvalidManifest(format!(
+            "unsupported value of `{}` in `{}`",
+            name, kind
+        ))),
+    }
+}
+
+/// Converts the argument parser error of a manifest instruction, keeping
+/// invalid pubkeys and amounts in their own category
+fn instruction_error(index: usize, err: clap::Error) -> Error {
+    match err
+        .source()
+        .and_then(|source| source.downcast_ref::<Error>())
+    {
+        Some(Error::InvalidPubkey(value)) => return Error::InvalidPubkey(value.clone()),
+        Some(Error::InvalidAmount(value)) => return Error::InvalidAmount(value.clone()),
+        _ => {}
+    }
+
+    // keep the message without the usage hints following it
+    let message = err.to_string();
+    let message = message.split("\n\n").next().unwrap_or_default();
+    let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    Error::InvalidManifest(format!(
+        "instruction #{}: {}",
+        index + 1,
+        message.trim_start_matches("error: ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use governance_upgrade_ix_base64_generator::error::exit_code;
+    use serde_json::json;
+
+    use super::*;
+
+    const ADDRESS: &str = "11111111111111111111111111111111";
+
+    fn instruction(value: Value) -> ManifestInstruction {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn manifest(instructions: Value) -> Manifest {
+        serde_json::from_value(json!({ "name": "test", "instructions": instructions })).unwrap()
+    }
+
+    #[test]
+    fn underscore_keys_become_dashed_flags() {
+        let args = instruction(json!({ "kind": "make-immutable", "program_data_dump": "dump" }))
+            .to_args()
+            .unwrap();
+        assert_eq!(args, ["make-immutable", "--program-data-dump", "dump"]);
+    }
+
+    #[test]
+    fn booleans_and_null() {
+        let args = instruction(json!({
+            "kind": "make-immutable",
+            "confirm_irreversible": true,
+            "checked": false,
+            "realm": null,
+        }))
+        .to_args()
+        .unwrap();
+        assert_eq!(args, ["make-immutable", "--confirm-irreversible"]);
+    }
+
+    #[test]
+    fn arrays_repeat_the_flag() {
+        let args = instruction(json!({ "kind": "create-proposal", "options": ["yes", "no"] }))
+            .to_args()
+            .unwrap();
+        assert_eq!(
+            args,
+            ["create-proposal", "--options", "yes", "--options", "no"]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        let args = instruction(json!({ "kind": "transfer-sol", "lamports": 1000, "sol": 1.5 }))
+            .to_args()
+            .unwrap();
+        assert_eq!(args, ["transfer-sol", "--lamports", "1000", "--sol", "1.5"]);
+    }
+
+    #[test]
+    fn reject_nested_objects() {
+        let err = instruction(json!({ "kind": "upgrade", "program": { "address": ADDRESS } }))
+            .to_args()
+            .unwrap_err();
+        assert!(matches!(&err, Error::InvalidManifest(message)
+            if message == "unsupported value of `program` in `upgrade`"));
+
+        let err = instruction(json!({ "kind": "create-proposal", "options": [{ "a": 1 }] }))
+            .to_args()
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidManifest(_)));
+    }
+
+    #[test]
+    fn invalid_pubkey_keeps_its_exit_code() {
+        let err = manifest(json!([{
+            "kind": "transfer-sol",
+            "governance": "not-a-pubkey",
+            "recipient": ADDRESS,
+            "lamports": 1,
+        }]))
+        .commands()
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidPubkey(_)));
+        assert_eq!(err.exit_code(), exit_code::INVALID_PUBKEY);
+    }
+
+    #[test]
+    fn invalid_amount_keeps_its_exit_code() {
+        let err = manifest(json!([{
+            "kind": "transfer-sol",
+            "governance": ADDRESS,
+            "recipient": ADDRESS,
+            "sol": "1.5.0",
+        }]))
+        .commands()
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidAmount(_)));
+        assert_eq!(err.exit_code(), exit_code::INVALID_AMOUNT);
+    }
+
+    #[test]
+    fn other_errors_name_the_instruction() {
+        let err = manifest(json!([
+            { "kind": "close-buffer", "buffer": ADDRESS, "recipient": ADDRESS, "authority": ADDRESS },
+            { "kind": "no-such-instruction" },
+        ]))
+        .commands()
+        .unwrap_err();
+        assert!(matches!(&err, Error::InvalidManifest(message)
+            if message.starts_with("instruction #2: unrecognized subcommand 'no-such-instruction'")));
+        assert_eq!(err.exit_code(), exit_code::INPUT);
+    }
+}
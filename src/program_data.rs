@@ -0,0 +1,208 @@
+use std::{fs, path::Path};
+
+use base64::{engine::general_purpose, Engine};
+use serde::Deserialize;
+use solana_program::{
+    bpf_loader_upgradeable::{extend_program, UpgradeableLoaderState},
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    instruction::Instruction,
+    pubkey::Pubkey,
+};
+
+use crate::error::Error;
+
+/// Account saved with `solana account <ADDRESS> --output json`
+#[derive(Debug, Deserialize)]
+struct AccountDump {
+    /// Address of the account
+    pubkey: String,
+    account: AccountDumpData,
+}
+
+#[derive(Debug, Deserialize)]
+struct AccountDumpData {
+    /// Encoded account data and the name of its encoding
+    data: (String, String),
+}
+
+/// Reads the data of a locally saved account, either as the JSON output of
+/// `solana account` or as the raw bytes written with `--output-file`. The
+/// JSON output is checked to be a snapshot of the expected address, raw bytes
+/// don't record it
+pub fn read_account_dump(path: &Path, address: &Pubkey) -> Result<Vec<u8>, Error> {
+    let bytes = fs::read(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    match serde_json::from_slice::<AccountDump>(&bytes) {
+        Ok(dump) => {
+            if dump.pubkey != address.to_string() {
+                return Err(Error::InvalidAccountData(format!(
+                    "snapshot of {}, expected {}",
+                    dump.pubkey, address
+                )));
+            }
+
+            let (data, encoding) = dump.account.data;
+            if encoding != "base64" {
+                return Err(Error::InvalidAccountData(format!(
+                    "unsupported data encoding `{}`, use base64",
+                    encoding
+                )));
+            }
+            Ok(general_purpose::STANDARD.decode(data)?)
+        }
+        Err(_) => Ok(bytes),
+    }
+}
+
+/// Returns the upgrade authority recorded in the ProgramData account, `None`
+/// for immutable programs
+pub fn upgrade_authority(program_data: &[u8]) -> Result<Option<Pubkey>, Error> {
+    let state: UpgradeableLoaderState = bincode::deserialize(program_data)
+        .map_err(|_| Error::InvalidAccountData("not an upgradeable loader account".into()))?;
+
+    match state {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        } => Ok(upgrade_authority_address),
+        _ => Err(Error::InvalidAccountData(
+            "not a ProgramData account".into(),
+        )),
+    }
+}
+
+/// Verifies that the ProgramData account is upgradeable by the authority
+pub fn check_upgrade_authority(program_data: &[u8], authority: &Pubkey) -> Result<(), Error> {
+    match upgrade_authority(program_data)? {
+        Some(actual) if actual == *authority => Ok(()),
+        actual => Err(Error::AuthorityMismatch {
+            expected: *authority,
+            actual,
+        }),
+    }
+}
+
+/// Returns the number of bytes the ProgramData account has to be extended by
+/// to fit a program binary of the given length
+pub fn required_extension(program_data: &[u8], program_len: usize) -> Result<usize, Error> {
+    // make sure the account is actually a ProgramData account
+    upgrade_authority(program_data)?;
+
+    Ok(UpgradeableLoaderState::size_of_programdata(program_len).saturating_sub(program_data.len()))
+}
+
+/// Returns the `ExtendProgram` instruction growing the ProgramData account to
+/// fit a program binary of the given length, if it is too small to hold it.
+/// The payer funds the rent of the extra bytes, so it is required then. The
+/// extension fails if it exceeds what a governance CPI can grow an account by
+pub fn extend_program_if_needed(
+    program_address: &Pubkey,
+    payer_address: Option<&Pubkey>,
+    program_data: &[u8],
+    program_len: usize,
+) -> Result<Option<Instruction>, Error> {
+    let additional_bytes = required_extension(program_data, program_len)?;
+    if additional_bytes == 0 {
+        return Ok(None);
+    }
+    // executed through governance the extension is a CPI, limited in size
+    if additional_bytes > MAX_PERMITTED_DATA_INCREASE {
+        return Err(Error::ExtensionTooLarge(
+            additional_bytes,
+            MAX_PERMITTED_DATA_INCREASE,
+        ));
+    }
+    let additional_bytes = additional_bytes as u32;
+    let payer_address = payer_address.ok_or(Error::MissingExtendPayer(additional_bytes))?;
+
+    Ok(Some(extend_program(
+        program_address,
+        Some(payer_address),
+        additional_bytes,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds ProgramData account data with room for a program of the given length
+    fn program_data(program_len: usize) -> Vec<u8> {
+        let mut data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 1,
+            upgrade_authority_address: Some(Pubkey::new_unique()),
+        })
+        .unwrap();
+        data.resize(UpgradeableLoaderState::size_of_programdata(program_len), 0);
+        data
+    }
+
+    #[test]
+    fn required_extension_of_smaller_binary() {
+        assert_eq!(required_extension(&program_data(1000), 999).unwrap(), 0);
+    }
+
+    #[test]
+    fn required_extension_of_equal_binary() {
+        let data = program_data(1000);
+        assert_eq!(data.len() - 45, 1000);
+        assert_eq!(required_extension(&data, 1000).unwrap(), 0);
+    }
+
+    #[test]
+    fn required_extension_of_larger_binary() {
+        assert_eq!(required_extension(&program_data(1000), 1100).unwrap(), 100);
+    }
+
+    #[test]
+    fn extend_program_within_cpi_limit() {
+        let payer = Pubkey::new_unique();
+        let instruction = extend_program_if_needed(
+            &Pubkey::new_unique(),
+            Some(&payer),
+            &program_data(1000),
+            1000 + MAX_PERMITTED_DATA_INCREASE,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(instruction.accounts.last().unwrap().pubkey, payer);
+    }
+
+    #[test]
+    fn extend_program_beyond_cpi_limit() {
+        assert!(matches!(
+            extend_program_if_needed(
+                &Pubkey::new_unique(),
+                Some(&Pubkey::new_unique()),
+                &program_data(1000),
+                1001 + MAX_PERMITTED_DATA_INCREASE,
+            ),
+            Err(Error::ExtensionTooLarge(10241, 10240))
+        ));
+    }
+
+    #[test]
+    fn extend_program_without_payer() {
+        assert!(matches!(
+            extend_program_if_needed(&Pubkey::new_unique(), None, &program_data(1000), 1100),
+            Err(Error::MissingExtendPayer(100))
+        ));
+    }
+
+    #[test]
+    fn required_extension_of_non_program_data() {
+        let buffer = bincode::serialize(&UpgradeableLoaderState::Buffer {
+            authority_address: None,
+        })
+        .unwrap();
+
+        assert!(matches!(
+            required_extension(&buffer, 1000),
+            Err(Error::InvalidAccountData(_))
+        ));
+    }
+}
@@ -0,0 +1,134 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey,
+    pubkey::Pubkey,
+};
+
+/// Address of the SPL Token program
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Address of the SPL Token-2022 program
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PTKN3ZhN8QrAqa");
+
+/// Address of the SPL Associated Token Account program
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Tag of the TransferChecked instruction, shared by both token programs
+const TRANSFER_CHECKED_TAG: u8 = 12;
+
+/// Returns the associated token account of the wallet for the mint of the token program
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Creates a TransferChecked instruction of the token program, the transfer
+/// fails on-chain if the decimals differ from the mint ones
+pub fn transfer_checked(
+    token_program: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let mut data = vec![TRANSFER_CHECKED_TAG];
+    data.extend(amount.to_le_bytes());
+    data.push(decimals);
+
+    Instruction {
+        program_id: *token_program,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::decoder::DecoderRegistry;
+
+    fn pubkey(value: &str) -> Pubkey {
+        Pubkey::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn associated_token_address() {
+        // pair from the associated token address test of @solana/spl-token
+        let mint = pubkey("7o36UsWR1JQLpZ9PE2gn9L4SQ69CNNiWAXd4Jt7rqz9Z");
+        let wallet = pubkey("B8UwBUUnKwCyKuGMbFKWaG7exYdDk2ozZrPg72NyVbfj");
+
+        assert_eq!(
+            get_associated_token_address(&wallet, &mint, &TOKEN_PROGRAM_ID),
+            pubkey("DShWnroshVbeUp28oopA3Pu7oFPDBtC1DBmPECXXAQ9n")
+        );
+        // the token program is part of the seeds, pinned against regressions
+        assert_eq!(
+            get_associated_token_address(&wallet, &mint, &TOKEN_2022_PROGRAM_ID),
+            pubkey("4Hj1HzUD54KPaNfuByDULBKJSYNBFAuFZSGaKw8eLbLx")
+        );
+    }
+
+    #[test]
+    fn decode_transfer_checked() {
+        let [source, mint, destination, authority] = [(); 4].map(|_| Pubkey::new_unique());
+
+        for (token_program, program_name) in [
+            (TOKEN_PROGRAM_ID, "SPL Token"),
+            (TOKEN_2022_PROGRAM_ID, "SPL Token-2022"),
+        ] {
+            let instruction = transfer_checked(
+                &token_program,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                1_500_000,
+                6,
+            );
+
+            let decoded = DecoderRegistry::default()
+                .decode(&instruction.into())
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.program_name, program_name);
+            assert_eq!(decoded.name, "TransferChecked");
+            assert_eq!(
+                decoded.fields,
+                vec![
+                    ("amount", "1500000".to_string()),
+                    ("decimals", "6".to_string()),
+                ]
+            );
+            assert_eq!(
+                decoded
+                    .accounts
+                    .iter()
+                    .map(|(role, account)| (*role, account.pubkey, account.is_signer))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("source", source, false),
+                    ("mint", mint, false),
+                    ("destination", destination, false),
+                    ("authority", authority, true),
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Transfers out of the treasuries of a governance
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey, system_instruction};
+
+use crate::{
+    governance::{
+        get_governance_authority_address, pda::get_native_treasury_address, GovernanceAuthority,
+    },
+    token::{get_associated_token_address, transfer_checked},
+};
+
+/// Creates a transfer of lamports from the native treasury of the governance,
+/// the treasury signs when the proposal is executed
+pub fn transfer_sol(
+    governance_program: &Pubkey,
+    governance: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let native_treasury = get_native_treasury_address(governance_program, governance);
+
+    system_instruction::transfer(&native_treasury, recipient, lamports)
+}
+
+/// Creates a checked token transfer between the associated token accounts of
+/// the governance account holding the tokens and of the recipient wallet
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens(
+    governance_program: &Pubkey,
+    governance: &Pubkey,
+    owner: GovernanceAuthority,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    amount: u64,
+    decimals: u8,
+) -> Instruction {
+    let owner = get_governance_authority_address(governance_program, governance, owner);
+
+    transfer_checked(
+        token_program,
+        &get_associated_token_address(&owner, mint, token_program),
+        mint,
+        &get_associated_token_address(recipient, mint, token_program),
+        &owner,
+        amount,
+        decimals,
+    )
+}